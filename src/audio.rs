@@ -0,0 +1,158 @@
+//! WAV reading and writing, downmixing and loudness normalization of PCM audio
+//! (`audio` feature)
+
+use std::io::Cursor;
+
+use crate::{ElevenLabsTTVError, PcmAudio};
+
+/// EBU R128 target loudness, in LUFS
+pub const EBU_R128_TARGET_LUFS: f64 = -23.0;
+
+impl PcmAudio {
+    /// Read a 16-bit PCM WAV file
+    pub fn from_wav(bytes: &[u8]) -> Result<Self, ElevenLabsTTVError> {
+        let reader = hound::WavReader::new(Cursor::new(bytes)).map_err(decode_error)?;
+        let spec = reader.spec();
+        if spec.sample_format != hound::SampleFormat::Int || spec.bits_per_sample != 16 {
+            return Err(ElevenLabsTTVError::AudioDecodeError(format!(
+                "unsupported WAV sample format ({:?}, {} bits), expected 16-bit PCM",
+                spec.sample_format, spec.bits_per_sample
+            )));
+        }
+
+        Ok(Self {
+            sample_rate: spec.sample_rate,
+            channels: spec.channels,
+            samples: reader
+                .into_samples::<i16>()
+                .collect::<Result<_, _>>()
+                .map_err(decode_error)?,
+        })
+    }
+
+    /// Encode the audio as a 16-bit PCM WAV file
+    pub fn to_wav(&self) -> Result<Vec<u8>, ElevenLabsTTVError> {
+        let spec = hound::WavSpec {
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = hound::WavWriter::new(&mut cursor, spec).map_err(encode_error)?;
+        for &sample in &self.samples {
+            writer.write_sample(sample).map_err(encode_error)?;
+        }
+        writer.finalize().map_err(encode_error)?;
+
+        Ok(cursor.into_inner())
+    }
+
+    /// Average all channels into a single one
+    pub fn to_mono(&self) -> Self {
+        let channels = self.channels as usize;
+        if channels <= 1 {
+            return self.clone();
+        }
+
+        Self {
+            sample_rate: self.sample_rate,
+            channels: 1,
+            samples: self
+                .samples
+                .chunks_exact(channels)
+                .map(|frame| {
+                    (frame.iter().map(|&s| i32::from(s)).sum::<i32>() / channels as i32) as i16
+                })
+                .collect(),
+        }
+    }
+
+    /// Integrated loudness in LUFS (EBU R128), or `None` for silent audio
+    pub fn loudness_lufs(&self) -> Result<Option<f64>, ElevenLabsTTVError> {
+        let mut meter =
+            ebur128::EbuR128::new(u32::from(self.channels), self.sample_rate, ebur128::Mode::I)
+                .map_err(loudness_error)?;
+        meter
+            .add_frames_i16(&self.samples)
+            .map_err(loudness_error)?;
+        let loudness = meter.loudness_global().map_err(loudness_error)?;
+
+        Ok(loudness.is_finite().then_some(loudness))
+    }
+
+    /// Apply gain so the integrated loudness is `target_lufs`, e.g. `EBU_R128_TARGET_LUFS`.
+    /// Level-matching previews this way keeps louder candidates from winning auditions
+    /// unfairly. Samples are clipped rather than limited; silent audio is returned as-is.
+    pub fn normalize_loudness(&self, target_lufs: f64) -> Result<Self, ElevenLabsTTVError> {
+        let Some(loudness) = self.loudness_lufs()? else {
+            return Ok(self.clone());
+        };
+        let gain = 10f64.powf((target_lufs - loudness) / 20.0);
+
+        Ok(Self {
+            sample_rate: self.sample_rate,
+            channels: self.channels,
+            samples: self
+                .samples
+                .iter()
+                .map(|&s| (f64::from(s) * gain).round().clamp(-32768.0, 32767.0) as i16)
+                .collect(),
+        })
+    }
+}
+
+fn decode_error(error: hound::Error) -> ElevenLabsTTVError {
+    ElevenLabsTTVError::AudioDecodeError(error.to_string())
+}
+
+fn loudness_error(error: ebur128::Error) -> ElevenLabsTTVError {
+    ElevenLabsTTVError::AudioDecodeError(format!("loudness measurement failed: {}", error))
+}
+
+fn encode_error(error: hound::Error) -> ElevenLabsTTVError {
+    ElevenLabsTTVError::AudioEncodeError(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wav_round_trip_and_mono() {
+        let stereo = PcmAudio {
+            sample_rate: 22050,
+            channels: 2,
+            samples: vec![100, 300, -200, -400, 1000, 1000],
+        };
+        assert_eq!(
+            PcmAudio::from_wav(&stereo.to_wav().unwrap()).unwrap(),
+            stereo
+        );
+
+        let mono = stereo.to_mono();
+        assert_eq!(mono.samples, vec![200, -300, 1000]);
+    }
+
+    #[test]
+    fn test_normalize_loudness() {
+        let tone = |amplitude: f64| PcmAudio {
+            sample_rate: 48000,
+            channels: 1,
+            samples: (0..48000)
+                .map(|i| {
+                    (amplitude * (i as f64 * 1000.0 * std::f64::consts::TAU / 48000.0).sin()) as i16
+                })
+                .collect(),
+        };
+
+        for amplitude in [1000.0, 20000.0] {
+            let normalized = tone(amplitude)
+                .normalize_loudness(EBU_R128_TARGET_LUFS)
+                .unwrap();
+            let loudness = normalized.loudness_lufs().unwrap().unwrap();
+            assert!((loudness - EBU_R128_TARGET_LUFS).abs() < 0.1);
+        }
+        assert_eq!(tone(0.0).loudness_lufs().unwrap(), None);
+    }
+}
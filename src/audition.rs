@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ElevenLabsTTVClient, TextToVoiceCreateVoiceBuilder};
+
+/// Tracks which previews were played and which one was chosen
+///
+/// Used to fill `played_not_selected_voice_ids` (RLHF feedback) on the Create Voice request.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuditionTracker {
+    played: Vec<String>,
+    selected: Option<String>,
+}
+
+impl AuditionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that a preview was played. Playing the same preview twice is recorded once.
+    pub fn mark_played<S: Into<String>>(&mut self, generated_voice_id: S) {
+        let generated_voice_id = generated_voice_id.into();
+        if !self.played.contains(&generated_voice_id) {
+            self.played.push(generated_voice_id);
+        }
+    }
+
+    /// Record the chosen preview. A selected preview is also considered played.
+    pub fn mark_selected<S: Into<String>>(&mut self, generated_voice_id: S) {
+        let generated_voice_id = generated_voice_id.into();
+        self.mark_played(generated_voice_id.clone());
+        self.selected = Some(generated_voice_id);
+    }
+
+    /// The chosen generated voice ID, if any
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    /// All played generated voice IDs, in the order they were first played
+    pub fn played(&self) -> &[String] {
+        &self.played
+    }
+
+    /// Played voice IDs, excluding the given (selected) one
+    pub fn played_not_selected(&self, selected_voice_id: &str) -> Vec<String> {
+        self.played
+            .iter()
+            .filter(|id| id.as_str() != selected_voice_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Start building a Create Voice request for the selected preview,
+    /// with `played_not_selected_voice_ids` already populated.
+    ///
+    /// Returns `None` if no preview has been selected yet.
+    pub fn create_voice<'a, S: Into<String>>(
+        &self,
+        client: &'a ElevenLabsTTVClient,
+        voice_name: S,
+        voice_description: S,
+    ) -> Option<TextToVoiceCreateVoiceBuilder<'a>> {
+        let selected = self.selected.clone()?;
+
+        Some(
+            client
+                .create_voice(voice_name.into(), voice_description.into(), selected)
+                .audition(self),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_played_not_selected() {
+        let mut tracker = AuditionTracker::new();
+        tracker.mark_played("a");
+        tracker.mark_played("b");
+        tracker.mark_played("a");
+        tracker.mark_selected("c");
+
+        assert_eq!(tracker.played(), ["a", "b", "c"]);
+        assert_eq!(tracker.selected(), Some("c"));
+        assert_eq!(tracker.played_not_selected("c"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_create_voice_populates_feedback() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let mut tracker = AuditionTracker::new();
+        assert!(
+            tracker
+                .create_voice(&client, "Mila", "Soft-spoken")
+                .is_none()
+        );
+
+        tracker.mark_played("a");
+        tracker.mark_selected("b");
+        let builder = tracker
+            .create_voice(&client, "Mila", "Soft-spoken")
+            .unwrap();
+
+        assert_eq!(builder.generated_voice_id, "b".to_string());
+        assert_eq!(
+            builder.played_not_selected_voice_ids,
+            Some(vec!["a".to_string()])
+        );
+    }
+}
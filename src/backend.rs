@@ -0,0 +1,173 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use bytes::Bytes;
+use http_body_util::BodyExt;
+use reqwest::{Request, Response};
+
+use crate::ElevenLabsTTVError;
+
+/// Boxed future returned by `HttpBackend::execute`
+pub type BackendFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<http::Response<Bytes>, ElevenLabsTTVError>> + Send + 'a>>;
+
+/// Transport executing the client's HTTP requests, set with
+/// `ElevenLabsTTVClientBuilder::http_backend`
+///
+/// Requests arrive fully prepared (URL, authentication and other headers, body); the backend
+/// only sends them and returns the buffered response. Implement it to run the client over
+/// another HTTP stack, e.g. hyper or ureq (via `spawn_blocking`).
+///
+/// This replaces the transport only: requests are still prepared with reqwest's types, so
+/// reqwest stays a dependency. Disabling the TLS features leaves out its TLS stack and
+/// connection handling.
+pub trait HttpBackend: Send + Sync {
+    fn execute(&self, request: http::Request<Bytes>) -> BackendFuture<'_>;
+}
+
+/// `HttpBackend` over a `reqwest::Client`, e.g. to share a client configured elsewhere
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestBackend {
+    client: reqwest::Client,
+}
+
+impl ReqwestBackend {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpBackend for ReqwestBackend {
+    fn execute(&self, request: http::Request<Bytes>) -> BackendFuture<'_> {
+        Box::pin(async move {
+            let request = Request::try_from(request.map(reqwest::Body::from))?;
+            let response = self.client.execute(request).await?;
+
+            let mut builder = http::Response::builder()
+                .status(response.status())
+                .version(response.version());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            builder
+                .body(response.bytes().await?)
+                .map_err(|e| ElevenLabsTTVError::BackendError(e.to_string()))
+        })
+    }
+}
+
+/// Execute a prepared request with a custom backend
+///
+/// Streamed bodies (multipart forms, file uploads) are read into memory first.
+pub(crate) async fn execute(
+    backend: &dyn HttpBackend,
+    mut request: Request,
+) -> Result<Response, ElevenLabsTTVError> {
+    let body = match request.body_mut().take() {
+        None => Bytes::new(),
+        Some(body) => match body.as_bytes() {
+            Some(bytes) => Bytes::copy_from_slice(bytes),
+            None => body.collect().await?.to_bytes(),
+        },
+    };
+    let mut builder = http::Request::builder()
+        .method(request.method().clone())
+        .uri(request.url().as_str())
+        .version(request.version());
+    if let Some(headers) = builder.headers_mut() {
+        *headers = request.headers().clone();
+    }
+    let request = builder
+        .body(body)
+        .map_err(|e| ElevenLabsTTVError::BackendError(e.to_string()))?;
+
+    Ok(Response::from(backend.execute(request).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::ElevenLabsTTVClient;
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl HttpBackend for Recorder {
+        fn execute(&self, request: http::Request<Bytes>) -> BackendFuture<'_> {
+            Box::pin(async move {
+                self.0.lock().unwrap().push(format!(
+                    "{} {} {:?}",
+                    request.method(),
+                    request.uri(),
+                    request.headers().get("xi-api-key")
+                ));
+                Ok(http::Response::new(Bytes::from_static(b"audio")))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_backend() {
+        let recorder = Recorder::default();
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .http_backend(recorder.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.stream_preview("gen-1").await.unwrap(), "audio");
+        assert_eq!(
+            recorder.0.lock().unwrap()[0],
+            "GET https://api.elevenlabs.io/v1/text-to-voice/gen-1/stream Some(Sensitive)"
+        );
+    }
+    /// Keeps the content type and body of each request
+    #[derive(Clone, Default)]
+    struct BodyRecorder(Arc<Mutex<Vec<(String, Bytes)>>>);
+
+    impl HttpBackend for BodyRecorder {
+        fn execute(&self, request: http::Request<Bytes>) -> BackendFuture<'_> {
+            Box::pin(async move {
+                let content_type = request
+                    .headers()
+                    .get(http::header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((content_type, request.into_body()));
+                Ok(http::Response::new(Bytes::from_static(
+                    br#"{"status": "ok"}"#,
+                )))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_backend_buffers_multipart_bodies() {
+        let recorder = BodyRecorder::default();
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .http_backend(recorder.clone())
+            .build()
+            .unwrap();
+        let recording =
+            std::env::temp_dir().join(format!("ttv-backend-{}.mp3", std::process::id()));
+        std::fs::write(&recording, "recorded captcha").unwrap();
+
+        let result = client
+            .submit_verification_recording("voice-1", &recording)
+            .await;
+        std::fs::remove_file(&recording).unwrap();
+
+        assert_eq!(result.unwrap().status, "ok");
+        let requests = recorder.0.lock().unwrap();
+        let (content_type, body) = &requests[0];
+        assert!(content_type.starts_with("multipart/form-data"));
+        let body = String::from_utf8_lossy(body);
+        assert!(body.contains("name=\"recording\""), "{}", body);
+        assert!(body.contains("recorded captcha"), "{}", body);
+    }
+}
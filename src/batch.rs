@@ -0,0 +1,400 @@
+//! Bulk voice creation: design, select and create every voice of a manifest
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), elevenlabs_ttv::ElevenLabsTTVError> {
+//! use elevenlabs_ttv::ElevenLabsTTVClient;
+//! use elevenlabs_ttv::batch::BatchManifest;
+//!
+//! let client = ElevenLabsTTVClient::new("your-api-key");
+//! let manifest = BatchManifest::load("voices.json")?;
+//! let state = client
+//!     .batch(manifest)
+//!     .concurrency(3)
+//!     .checkpoint("voices.state.json")
+//!     .run()
+//!     .await?;
+//! println!("{} created, {} failed", state.created().count(), state.failed().count());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinSet;
+
+use crate::selector::First;
+use crate::{
+    AdaptiveScheduler, ElevenLabsTTVClient, ElevenLabsTTVError, PreviewSelector, ProgressReporter,
+    TTVCreateVoiceRequest, TTVDesignVoiceRequest,
+};
+
+/// One voice to create: the name it gets and the design request generating its previews
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchEntry {
+    /// Name of the created voice. Must be unique within the manifest.
+    pub voice_name: String,
+    /// Design parameters, sent as-is (parameters left out use the API defaults)
+    pub request: TTVDesignVoiceRequest,
+    /// Labels (metadata) added to the created voice
+    #[serde(default)]
+    pub labels: Option<String>,
+}
+
+impl BatchEntry {
+    pub fn new<S: Into<String>>(voice_name: S, request: TTVDesignVoiceRequest) -> Self {
+        Self {
+            voice_name: voice_name.into(),
+            request,
+            labels: None,
+        }
+    }
+}
+
+/// The list of voices to create, stored as a JSON array of `BatchEntry`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BatchManifest {
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchManifest {
+    pub fn new(entries: Vec<BatchEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Load a manifest from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+/// Progress of one manifest entry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntryState {
+    /// The preview selected after designing, set once the design step succeeded
+    pub generated_voice_id: Option<String>,
+    /// The created voice, set once the entry is done
+    pub voice_id: Option<String>,
+    /// The last error, cleared when the entry is retried successfully
+    pub error: Option<String>,
+}
+
+/// Progress of a batch run, keyed by voice name. Written to the checkpoint file after
+/// every step, so an interrupted run resumes where it left off.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatchState {
+    pub entries: BTreeMap<String, EntryState>,
+}
+
+impl BatchState {
+    /// Load a checkpoint written by a previous run
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Write the state atomically (to a temporary file renamed over `path`)
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTVError> {
+        let path = path.as_ref();
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        fs::write(&temp, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    /// Created voices as (voice name, voice ID)
+    pub fn created(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .filter_map(|(name, entry)| Some((name.as_str(), entry.voice_id.as_deref()?)))
+    }
+
+    /// Failed entries as (voice name, error)
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .filter_map(|(name, entry)| match (&entry.voice_id, &entry.error) {
+                (None, Some(error)) => Some((name.as_str(), error.as_str())),
+                _ => None,
+            })
+    }
+}
+
+/// Builder for a batch run, created with `ElevenLabsTTVClient::batch`
+pub struct BatchBuilder<'a, P = First> {
+    client: &'a ElevenLabsTTVClient,
+    manifest: BatchManifest,
+    selector: P,
+    pub concurrency: usize,
+    pub checkpoint: Option<PathBuf>,
+    pub progress: Option<Arc<dyn ProgressReporter>>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start a batch run designing, selecting and creating every voice of the manifest
+    pub fn batch(&self, manifest: BatchManifest) -> BatchBuilder<'_> {
+        BatchBuilder {
+            client: self,
+            manifest,
+            selector: First,
+            concurrency: 3,
+            checkpoint: None,
+            progress: None,
+        }
+    }
+}
+
+impl<'a, P> BatchBuilder<'a, P>
+where
+    P: PreviewSelector + Send + Sync + 'static,
+{
+    /// Maximum number of voices processed at once. Defaults to 3; lowered automatically
+    /// when the API reports a lower concurrency limit (see `AdaptiveScheduler`).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// File the progress is saved to after every step. If it exists, the run resumes from it:
+    /// created voices are skipped and designed ones go straight to creation.
+    pub fn checkpoint<T: AsRef<Path>>(mut self, path: T) -> Self {
+        self.checkpoint = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Strategy picking the preview to create for each voice. Defaults to `First`
+    pub fn selector<S>(self, selector: S) -> BatchBuilder<'a, S>
+    where
+        S: PreviewSelector + Send + Sync + 'static,
+    {
+        BatchBuilder {
+            client: self.client,
+            manifest: self.manifest,
+            selector,
+            concurrency: self.concurrency,
+            checkpoint: self.checkpoint,
+            progress: self.progress,
+        }
+    }
+
+    /// Report per-voice progress, keyed by voice name
+    pub fn progress(mut self, progress: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Run the batch. Failures of single entries are recorded in the returned state
+    /// (and the checkpoint) rather than aborting the run; errors are only returned for an
+    /// invalid manifest or an unreadable/unwritable checkpoint.
+    pub async fn run(self) -> Result<BatchState, ElevenLabsTTVError> {
+        let mut names = std::collections::HashSet::new();
+        for entry in &self.manifest.entries {
+            if !names.insert(entry.voice_name.as_str()) {
+                return Err(ElevenLabsTTVError::ValidationError(format!(
+                    "duplicate voice_name '{}' in batch manifest",
+                    entry.voice_name
+                )));
+            }
+        }
+
+        let state = match &self.checkpoint {
+            Some(path) if path.exists() => BatchState::load(path)?,
+            _ => BatchState::default(),
+        };
+        let state = Arc::new(Mutex::new(state));
+        let scheduler = Arc::new(AdaptiveScheduler::new(self.client, self.concurrency));
+        let selector = Arc::new(self.selector);
+        let run = Arc::new(Run {
+            client: self.client.clone(),
+            state: state.clone(),
+            checkpoint: self.checkpoint,
+        });
+
+        let mut jobs = JoinSet::new();
+        for entry in self.manifest.entries {
+            let done = run.entry_state(&entry.voice_name).voice_id.is_some();
+            if done {
+                continue;
+            }
+
+            let (run, scheduler, selector, progress) = (
+                run.clone(),
+                scheduler.clone(),
+                selector.clone(),
+                self.progress.clone(),
+            );
+            jobs.spawn(async move {
+                scheduler
+                    .run(async {
+                        if let Some(progress) = &progress {
+                            progress.start(&entry.voice_name, None);
+                        }
+                        let result = run.process(&entry, selector.as_ref()).await;
+                        if let Some(progress) = &progress {
+                            progress.finish(&entry.voice_name);
+                        }
+                        result
+                    })
+                    .await
+            });
+        }
+
+        while let Some(result) = jobs.join_next().await {
+            result
+                .map_err(|e| ElevenLabsTTVError::IoError(std::io::Error::other(e.to_string())))??;
+        }
+
+        let state = state.lock().unwrap().clone();
+        Ok(state)
+    }
+}
+
+/// State shared by the jobs of a batch run
+struct Run {
+    client: ElevenLabsTTVClient,
+    state: Arc<Mutex<BatchState>>,
+    checkpoint: Option<PathBuf>,
+}
+
+impl Run {
+    fn entry_state(&self, voice_name: &str) -> EntryState {
+        self.state
+            .lock()
+            .unwrap()
+            .entries
+            .get(voice_name)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Update an entry and write the checkpoint
+    fn update<F: FnOnce(&mut EntryState)>(
+        &self,
+        voice_name: &str,
+        f: F,
+    ) -> Result<(), ElevenLabsTTVError> {
+        let mut state = self.state.lock().unwrap();
+        f(state.entries.entry(voice_name.to_string()).or_default());
+        match &self.checkpoint {
+            Some(path) => state.save(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Design, select and create one entry, resuming after the last completed step.
+    /// Only checkpoint errors are returned; API errors are recorded in the entry.
+    async fn process<P: PreviewSelector>(
+        &self,
+        entry: &BatchEntry,
+        selector: &P,
+    ) -> Result<(), ElevenLabsTTVError> {
+        let name = &entry.voice_name;
+
+        let generated_voice_id = match self.entry_state(name).generated_voice_id {
+            Some(generated_voice_id) => generated_voice_id,
+            None => match self.design(entry, selector).await {
+                Ok(generated_voice_id) => {
+                    self.update(name, |state| {
+                        state.generated_voice_id = Some(generated_voice_id.clone());
+                        state.error = None;
+                    })?;
+                    generated_voice_id
+                }
+                Err(error) => {
+                    return self.update(name, |state| state.error = Some(error.to_string()));
+                }
+            },
+        };
+
+        let request = TTVCreateVoiceRequest {
+            voice_name: name.clone(),
+            voice_description: entry.request.voice_description.clone(),
+            generated_voice_id,
+            labels: entry.labels.clone(),
+            played_not_selected_voice_ids: None,
+            extra_body: Default::default(),
+            extra_query: Vec::new(),
+        };
+        match self.client.execute_create_voice(request).await {
+            Ok(voice) => self.update(name, |state| {
+                state.voice_id = Some(voice.voice_id);
+                state.error = None;
+            }),
+            Err(error) => self.update(name, |state| state.error = Some(error.to_string())),
+        }
+    }
+
+    async fn design<P: PreviewSelector>(
+        &self,
+        entry: &BatchEntry,
+        selector: &P,
+    ) -> Result<String, ElevenLabsTTVError> {
+        entry.request.validate()?;
+        let response = self
+            .client
+            .execute_design_voice(entry.request.clone())
+            .await?;
+        let index = selector.select(&response.previews).await.ok_or_else(|| {
+            ElevenLabsTTVError::ValidationError("no preview was selected".to_string())
+        })?;
+
+        response
+            .previews
+            .into_iter()
+            .nth(index)
+            .map(|preview| preview.generated_voice_id)
+            .ok_or_else(|| {
+                ElevenLabsTTVError::ValidationError(format!(
+                    "selected preview {} does not exist",
+                    index
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_and_state_roundtrip() {
+        let manifest: BatchManifest = serde_json::from_value(serde_json::json!([
+            {"voice_name": "Guard", "request": {"voice_description": "Gruff city guard", "seed": 7}},
+            {"voice_name": "Witch", "request": {"voice_description": "Cackling old witch"}, "labels": "{\"role\":\"villain\"}"}
+        ]))
+        .unwrap();
+        assert_eq!(manifest.entries.len(), 2);
+        assert_eq!(manifest.entries[0].request.seed, Some(7));
+
+        let path = std::env::temp_dir().join(format!("ttv-batch-{}.json", std::process::id()));
+        let mut state = BatchState::default();
+        state.entries.insert(
+            "Guard".to_string(),
+            EntryState {
+                generated_voice_id: Some("gen-1".to_string()),
+                voice_id: Some("voice-1".to_string()),
+                error: None,
+            },
+        );
+        state.entries.insert(
+            "Witch".to_string(),
+            EntryState {
+                error: Some("API error (500): boom".to_string()),
+                ..Default::default()
+            },
+        );
+        state.save(&path).unwrap();
+
+        let loaded = BatchState::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, state);
+        assert_eq!(loaded.created().collect::<Vec<_>>(), [("Guard", "voice-1")]);
+        assert_eq!(loaded.failed().count(), 1);
+    }
+}
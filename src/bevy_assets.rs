@@ -0,0 +1,73 @@
+//! Bevy `AudioSource` assets from preview audio (`bevy` feature), to audition designed NPC
+//! voices inside a game during development
+//!
+//! Bevy plays MP3 (the default output format), WAV and Ogg Vorbis previews. Request one of
+//! these formats when designing voices for the engine; raw PCM and μ-law previews are not
+//! playable as they are.
+
+use bevy_asset::{Assets, Handle};
+use bevy_audio::AudioSource;
+
+use crate::{DecodedVoicePreview, TTVDesignVoiceDecodedResponse};
+
+impl From<DecodedVoicePreview> for AudioSource {
+    fn from(preview: DecodedVoicePreview) -> Self {
+        AudioSource {
+            bytes: Vec::from(preview.audio).into(),
+        }
+    }
+}
+
+impl DecodedVoicePreview {
+    /// Add the preview audio to `assets`, e.g. `ResMut<Assets<AudioSource>>`, and return its
+    /// handle to play with an `AudioPlayer`
+    pub fn add_audio_source(self, assets: &mut Assets<AudioSource>) -> Handle<AudioSource> {
+        assets.add(AudioSource::from(self))
+    }
+}
+
+impl TTVDesignVoiceDecodedResponse {
+    /// Add every preview to `assets`, returning the handles with their generated voice IDs,
+    /// in preview order
+    pub fn add_audio_sources(
+        self,
+        assets: &mut Assets<AudioSource>,
+    ) -> Vec<(String, Handle<AudioSource>)> {
+        self.previews
+            .into_iter()
+            .map(|preview| {
+                let generated_voice_id = preview.generated_voice_id.clone();
+                (generated_voice_id, preview.add_audio_source(assets))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_add_audio_sources_keeps_preview_order() {
+        let preview = |id: &str, audio: &'static [u8]| DecodedVoicePreview {
+            audio: Bytes::from_static(audio),
+            generated_voice_id: id.to_string(),
+            media_type: "audio/mpeg".into(),
+            duration_secs: 1.0,
+            language: None,
+        };
+        let response = TTVDesignVoiceDecodedResponse {
+            previews: vec![preview("gen-1", b"one"), preview("gen-2", b"two")],
+            text: "Preview text".to_string(),
+        };
+
+        let mut assets = Assets::<AudioSource>::default();
+        let handles = response.add_audio_sources(&mut assets);
+
+        assert_eq!(handles.len(), 2);
+        assert_eq!(handles[1].0, "gen-2");
+        assert_eq!(&*assets.get(&handles[1].1).unwrap().bytes, b"two");
+    }
+}
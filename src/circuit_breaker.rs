@@ -0,0 +1,145 @@
+//! Circuit breaker failing requests fast while the API is degraded, so a struggling upstream
+//! does not pile up slow design calls. See `ElevenLabsTTVClientBuilder::circuit_breaker`.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError};
+
+/// State of a client's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent normally
+    Closed,
+    /// Requests fail with `CircuitOpenError` without being sent
+    Open,
+    /// The open duration elapsed: the next request is sent as a probe, closing the circuit
+    /// if it succeeds and reopening it if it fails
+    HalfOpen,
+}
+
+/// Consecutive failures and open/probe times, shared by all clones of a client
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// Start of the probe in flight while half-open. A probe that never reports back (e.g. a
+    /// dropped future) stops blocking others after `open_duration`.
+    probe_started: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    /// Let a request through, or fail with `CircuitOpenError` while the circuit is open or
+    /// another request is probing it
+    pub(crate) fn acquire(&self) -> Result<(), ElevenLabsTTVError> {
+        let mut state = self.state.lock().unwrap();
+        let Some(opened_at) = state.opened_at else {
+            return Ok(());
+        };
+
+        let open_for = opened_at.elapsed();
+        if open_for < self.open_duration {
+            let remaining = self.open_duration - open_for;
+            return Err(ElevenLabsTTVError::CircuitOpenError {
+                retry_after: Some(remaining.as_secs_f64().ceil() as u64),
+            });
+        }
+        if state
+            .probe_started
+            .is_some_and(|started| started.elapsed() < self.open_duration)
+        {
+            return Err(ElevenLabsTTVError::CircuitOpenError { retry_after: None });
+        }
+
+        state.probe_started = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Record a request that got a non-5xx response, closing the circuit
+    pub(crate) fn record_success(&self) {
+        *self.state.lock().unwrap() = BreakerState::default();
+    }
+
+    /// Record a 5xx response or a request that failed without a response (e.g. a timeout),
+    /// opening the circuit after `failure_threshold` in a row or a failed probe
+    pub(crate) fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+
+        if state.probe_started.is_some() || state.consecutive_failures >= self.failure_threshold {
+            if state.probe_started.is_none() {
+                crate::logging::circuit_opened(state.consecutive_failures, self.open_duration);
+            }
+            state.opened_at = Some(Instant::now());
+            state.probe_started = None;
+        }
+    }
+
+    pub(crate) fn state(&self) -> CircuitState {
+        let state = self.state.lock().unwrap();
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// State of the circuit breaker, or `None` without one
+    /// (see `ElevenLabsTTVClientBuilder::circuit_breaker`)
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner
+            .circuit_breaker
+            .as_ref()
+            .map(CircuitBreaker::state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breaker_opens_then_probes() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(20));
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            breaker.acquire(),
+            Err(ElevenLabsTTVError::CircuitOpenError {
+                retry_after: Some(1)
+            })
+        ));
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker.acquire().unwrap();
+        assert!(breaker.acquire().is_err());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(25));
+        breaker.acquire().unwrap();
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.acquire().unwrap();
+    }
+}
@@ -0,0 +1,78 @@
+//! Load requests from configuration files (requires the `config` feature)
+//!
+//! Lets voice definitions live in version-controlled files, e.g. `villain.toml`:
+//!
+//! ```toml
+//! voice_description = "Cold, calculating male, 40s, slight Eastern European accent"
+//! model_id = "eleven_ttv_v3"
+//! guidance_scale = 8
+//! seed = 1234
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use crate::{ElevenLabsTTVError, TTVDesignVoiceRequest};
+
+impl TTVDesignVoiceRequest {
+    /// Load a design request from a TOML file
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        Self::from_toml_str(&fs::read_to_string(path)?)
+    }
+
+    /// Parse a design request from a TOML string
+    pub fn from_toml_str(toml: &str) -> Result<Self, ElevenLabsTTVError> {
+        toml::from_str(toml).map_err(|e| ElevenLabsTTVError::ConfigError(e.to_string()))
+    }
+
+    /// Load a design request from a JSON file
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        Self::from_json_str(&fs::read_to_string(path)?)
+    }
+
+    /// Parse a design request from a JSON string
+    pub fn from_json_str(json: &str) -> Result<Self, ElevenLabsTTVError> {
+        serde_json::from_str(json).map_err(|e| ElevenLabsTTVError::ConfigError(e.to_string()))
+    }
+
+    /// Load a design request from a YAML file
+    pub fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        Self::from_yaml_str(&fs::read_to_string(path)?)
+    }
+
+    /// Parse a design request from a YAML string
+    pub fn from_yaml_str(yaml: &str) -> Result<Self, ElevenLabsTTVError> {
+        serde_yaml::from_str(yaml).map_err(|e| ElevenLabsTTVError::ConfigError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_from_config_formats() {
+        let toml = TTVDesignVoiceRequest::from_toml_str(
+            "voice_description = \"Cheerful shopkeeper\"\nguidance_scale = 8\n",
+        )
+        .unwrap();
+        let yaml = TTVDesignVoiceRequest::from_yaml_str(
+            "voice_description: Cheerful shopkeeper\nguidance_scale: 8\n",
+        )
+        .unwrap();
+        let json = TTVDesignVoiceRequest::from_json_str(
+            r#"{"voice_description": "Cheerful shopkeeper", "guidance_scale": 8}"#,
+        )
+        .unwrap();
+
+        assert_eq!(toml, yaml);
+        assert_eq!(yaml, json);
+        assert_eq!(toml.guidance_scale, Some(8));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let error = TTVDesignVoiceRequest::from_toml_str("guidance_scale = 8").unwrap_err();
+        assert!(matches!(error, ElevenLabsTTVError::ConfigError(_)));
+    }
+}
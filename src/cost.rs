@@ -0,0 +1,130 @@
+//! Rough credit estimates for Design Voice calls, e.g. to show "this run will cost ~N credits"
+//! before executing it
+//!
+//! Previews are billed like speech: per character of the preview text, for each preview,
+//! at the rate of the model. Actual charges depend on the plan, so treat the numbers as
+//! estimates.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), elevenlabs_ttv::ElevenLabsTTVError> {
+//! use elevenlabs_ttv::ElevenLabsTTVClient;
+//!
+//! let client = ElevenLabsTTVClient::new("your-api-key");
+//! let request = client.design_voice("Calm, deep-voiced narrator").build()?;
+//! let estimate = request.estimate_cost();
+//! println!("This run will cost {}", estimate);
+//! client.ensure_quota(estimate.credits()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::TTVDesignVoiceRequest;
+
+/// Previews generated by a Design Voice call
+pub const PREVIEWS_PER_DESIGN: u32 = 3;
+
+/// Length assumed for auto-generated preview text, in characters
+pub const AUTO_GENERATED_TEXT_CHARACTERS: u64 = 200;
+
+/// Credits charged per character by a model: half for Flash and Turbo models, one otherwise
+pub fn credits_per_character(model_id: Option<&str>) -> f64 {
+    match model_id {
+        Some(model_id) if model_id.contains("flash") || model_id.contains("turbo") => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Estimated consumption of a Design Voice call
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimate {
+    /// Characters of preview text, per preview
+    pub characters: u64,
+    pub previews: u32,
+    pub credits_per_character: f64,
+}
+
+impl CostEstimate {
+    /// Estimate for a different number of previews, e.g. when previews are generated one by one
+    pub fn previews(mut self, previews: u32) -> Self {
+        self.previews = previews;
+        self
+    }
+
+    /// Estimated credits, rounded up
+    pub fn credits(&self) -> u64 {
+        (self.characters as f64 * self.previews as f64 * self.credits_per_character).ceil() as u64
+    }
+}
+
+impl fmt::Display for CostEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "~{} credits ({} previews of {} characters)",
+            self.credits(),
+            self.previews,
+            self.characters
+        )
+    }
+}
+
+impl TTVDesignVoiceRequest {
+    /// Estimate the credits consumed by this request, see the `cost` module
+    pub fn estimate_cost(&self) -> CostEstimate {
+        let characters = match &self.text {
+            Some(text) if self.auto_generate_text != Some(true) => text.chars().count() as u64,
+            _ => AUTO_GENERATED_TEXT_CHARACTERS,
+        };
+
+        CostEstimate {
+            characters,
+            previews: PREVIEWS_PER_DESIGN,
+            credits_per_character: credits_per_character(self.model_id.as_deref()),
+        }
+    }
+}
+
+/// Total estimated credits of several requests, e.g. the entries of a batch
+pub fn total_credits<'a, I>(requests: I) -> u64
+where
+    I: IntoIterator<Item = &'a TTVDesignVoiceRequest>,
+{
+    requests
+        .into_iter()
+        .map(|request| request.estimate_cost().credits())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElevenLabsTTVClient;
+
+    #[test]
+    fn test_estimate_cost() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let auto = client
+            .design_voice("Calm, deep-voiced narrator")
+            .build()
+            .unwrap();
+        assert_eq!(auto.estimate_cost().credits(), 600);
+
+        let flash = client
+            .design_voice("Calm, deep-voiced narrator")
+            .text("a".repeat(101))
+            .model("eleven_flash_v2_5")
+            .build()
+            .unwrap();
+        let estimate = flash.estimate_cost();
+        assert_eq!(estimate.credits(), 152);
+        assert_eq!(estimate.previews(1).credits(), 51);
+        assert_eq!(
+            estimate.to_string(),
+            "~152 credits (3 previews of 101 characters)"
+        );
+
+        assert_eq!(total_credits([&auto, &flash]), 752);
+    }
+}
@@ -0,0 +1,122 @@
+//! Decoding of compressed preview audio to PCM (`decode` feature)
+
+use std::io::Cursor;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::{ElevenLabsTTVError, PcmAudio, TTVDesignVoiceResponseVoicePreview};
+
+impl PcmAudio {
+    /// Decode a compressed audio file (MP3, WAV, FLAC or OGG Vorbis) to 16-bit PCM
+    ///
+    /// mime_type: Optional hint for the container format, e.g. "audio/mpeg".
+    /// Opus is not supported by the decoder and fails with `AudioDecodeError`.
+    pub fn decode(bytes: &[u8], mime_type: Option<&str>) -> Result<Self, ElevenLabsTTVError> {
+        let source =
+            MediaSourceStream::new(Box::new(Cursor::new(bytes.to_vec())), Default::default());
+        let mut hint = Hint::new();
+        if let Some(mime_type) = mime_type {
+            hint.mime_type(mime_type);
+        }
+
+        let mut format = symphonia::default::get_probe()
+            .format(
+                &hint,
+                source,
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .map_err(decode_error)?
+            .format;
+        let track = format.default_track().ok_or_else(|| {
+            ElevenLabsTTVError::AudioDecodeError("no audio track found".to_string())
+        })?;
+        let track_id = track.id;
+        let mut sample_rate = track.codec_params.sample_rate;
+        let mut channels = track
+            .codec_params
+            .channels
+            .map(|channels| channels.count() as u16);
+        let mut decoder = symphonia::default::get_codecs()
+            .make(&track.codec_params, &DecoderOptions::default())
+            .map_err(decode_error)?;
+
+        let mut samples = Vec::new();
+        loop {
+            let packet = match format.next_packet() {
+                Ok(packet) => packet,
+                Err(Error::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(decode_error(e)),
+            };
+            if packet.track_id() != track_id {
+                continue;
+            }
+
+            match decoder.decode(&packet) {
+                Ok(decoded) => {
+                    let spec = *decoded.spec();
+                    sample_rate = Some(spec.rate);
+                    channels = Some(spec.channels.count() as u16);
+                    let mut buffer = SampleBuffer::<i16>::new(decoded.capacity() as u64, spec);
+                    buffer.copy_interleaved_ref(decoded);
+                    samples.extend_from_slice(buffer.samples());
+                }
+                // Skip corrupt frames, as players do
+                Err(Error::DecodeError(_)) => continue,
+                Err(e) => return Err(decode_error(e)),
+            }
+        }
+
+        match (sample_rate, channels) {
+            (Some(sample_rate), Some(channels)) if channels > 0 => Ok(Self {
+                sample_rate,
+                channels,
+                samples,
+            }),
+            _ => Err(ElevenLabsTTVError::AudioDecodeError(
+                "unknown sample rate or channel count".to_string(),
+            )),
+        }
+    }
+}
+
+impl TTVDesignVoiceResponseVoicePreview {
+    /// Decode the (MP3) preview audio to PCM samples, e.g. for waveform display, silence
+    /// trimming or loudness analysis. For `pcm_*` output formats, use `pcm_audio` instead.
+    pub fn decode_pcm(&self) -> Result<PcmAudio, ElevenLabsTTVError> {
+        PcmAudio::decode(&self.decode_audio()?, Some(self.media_type.as_str()))
+    }
+}
+
+fn decode_error(error: Error) -> ElevenLabsTTVError {
+    ElevenLabsTTVError::AudioDecodeError(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_wav() {
+        let mut wav = b"RIFF\x28\0\0\0WAVEfmt ".to_vec();
+        wav.extend_from_slice(&[16, 0, 0, 0, 1, 0, 1, 0]);
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&32000u32.to_le_bytes());
+        wav.extend_from_slice(&[2, 0, 16, 0]);
+        wav.extend_from_slice(b"data\x04\0\0\0");
+        wav.extend_from_slice(&[0x01, 0x00, 0xff, 0xff]);
+
+        let audio = PcmAudio::decode(&wav, Some("audio/wav")).unwrap();
+        assert_eq!(audio.sample_rate, 16000);
+        assert_eq!(audio.channels, 1);
+        assert_eq!(audio.samples, vec![1, -1]);
+
+        assert!(PcmAudio::decode(b"not audio", None).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use serde::Deserialize;
+use serde::de::{self, Deserializer, Visitor};
+
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, LanguageCode, MediaType, TTVDesignVoiceRequest,
+    TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview, TextToVoiceDesignVoiceBuilder,
+};
+
+/// Design Voice response with the preview audio already decoded
+///
+/// Previews are base64-decoded while the JSON is parsed, straight from the response body,
+/// so the encoded audio is never copied into a `String`. Peak memory is roughly the body
+/// plus the decoded audio, instead of the body, the encoded strings and the decoded audio.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TTVDesignVoiceDecodedResponse {
+    pub previews: Vec<DecodedVoicePreview>,
+    /// The text used to preview the voices
+    pub text: String,
+}
+
+/// A Design Voice preview with its audio decoded, see `TTVDesignVoiceDecodedResponse`
+#[derive(Debug, Clone, Deserialize)]
+pub struct DecodedVoicePreview {
+    /// The raw preview audio (in `media_type` format)
+    #[serde(rename = "audio_base_64", deserialize_with = "base64_bytes")]
+    pub audio: Bytes,
+    /// The ID of the generated voice. Use it to create a voice from the preview
+    pub generated_voice_id: String,
+    /// The media type of the preview
+    pub media_type: MediaType,
+    /// The duration of the preview in seconds
+    pub duration_secs: f64,
+    /// The language of the preview (can be None)
+    pub language: Option<LanguageCode>,
+}
+
+impl TryFrom<TTVDesignVoiceResponseVoicePreview> for DecodedVoicePreview {
+    type Error = ElevenLabsTTVError;
+
+    /// Decode the preview audio, dropping the encoded string
+    fn try_from(preview: TTVDesignVoiceResponseVoicePreview) -> Result<Self, Self::Error> {
+        Ok(Self {
+            audio: preview.decode_audio()?,
+            generated_voice_id: preview.generated_voice_id,
+            media_type: preview.media_type,
+            duration_secs: preview.duration_secs,
+            language: preview.language,
+        })
+    }
+}
+
+impl TTVDesignVoiceResponse {
+    /// The previews, each base64-decoded only when the iterator reaches it
+    ///
+    /// Each encoded string is dropped once decoded, and skipped previews are never decoded,
+    /// which keeps peak memory low when only one preview is kept, e.g.
+    /// `response.previews_decoded().find(...)`.
+    pub fn previews_decoded(
+        self,
+    ) -> impl Iterator<Item = Result<DecodedVoicePreview, ElevenLabsTTVError>> {
+        self.previews.into_iter().map(DecodedVoicePreview::try_from)
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// Execute a Design Voice request, decoding previews while the response is parsed
+    pub async fn execute_design_voice_decoded(
+        &self,
+        request: TTVDesignVoiceRequest,
+    ) -> Result<TTVDesignVoiceDecodedResponse, ElevenLabsTTVError> {
+        let endpoint = "text-to-voice/design";
+        let response: TTVDesignVoiceDecodedResponse = self
+            .send_json(endpoint, self.design_voice_request(&request)?)
+            .await?;
+
+        let audio_bytes = response
+            .previews
+            .iter()
+            .map(|preview| preview.audio.len() as u64)
+            .sum();
+        self.record_audio_bytes(endpoint, audio_bytes);
+
+        Ok(response)
+    }
+}
+
+impl<'a> TextToVoiceDesignVoiceBuilder<'a> {
+    /// Execute the Text-to-Voice: Design Voice request, returning previews as decoded audio.
+    /// Uses less memory than `execute` followed by `decode_audio` for large previews.
+    pub async fn execute_decoded(
+        self,
+    ) -> Result<TTVDesignVoiceDecodedResponse, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+
+        client.execute_design_voice_decoded(request).await
+    }
+}
+
+/// Deserialize a base64 string into bytes, borrowing the string from the input when possible
+fn base64_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bytes, D::Error> {
+    struct Base64Visitor;
+
+    impl Visitor<'_> for Base64Visitor {
+        type Value = Bytes;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "base64-encoded audio")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Bytes, E> {
+            STANDARD.decode(value).map(Bytes::from).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(Base64Visitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previews_are_decoded_while_parsing() {
+        let body = br#"{"previews":[{"audio_base_64":"aGVsbG8=","generated_voice_id":"gen-1",
+            "media_type":"audio/mpeg","duration_secs":1.5,"language":"en"}],"text":"Hi"}"#;
+        let response: TTVDesignVoiceDecodedResponse = serde_json::from_slice(body).unwrap();
+        assert_eq!(response.previews[0].audio, "hello");
+
+        let invalid = br#"{"previews":[{"audio_base_64":"!!","generated_voice_id":"gen-1",
+            "media_type":"audio/mpeg","duration_secs":1.5,"language":null}],"text":"Hi"}"#;
+        assert!(serde_json::from_slice::<TTVDesignVoiceDecodedResponse>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_previews_decoded_lazily() {
+        let response: TTVDesignVoiceResponse = serde_json::from_value(serde_json::json!({
+            "previews": [
+                { "audio_base_64": "aGVsbG8=", "generated_voice_id": "gen-1",
+                  "media_type": "audio/mpeg", "duration_secs": 1.5, "language": "en" },
+                { "audio_base_64": "!!", "generated_voice_id": "gen-2",
+                  "media_type": "audio/mpeg", "duration_secs": 1.5, "language": "en" }
+            ],
+            "text": "Hi"
+        }))
+        .unwrap();
+
+        let mut previews = response.previews_decoded();
+        assert_eq!(previews.next().unwrap().unwrap().audio, "hello");
+        assert!(previews.next().unwrap().is_err());
+        assert!(previews.next().is_none());
+    }
+}
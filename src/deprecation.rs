@@ -0,0 +1,118 @@
+//! Detect requests targeting deprecated or retired models before sending them, so long-lived
+//! services notice upcoming breakage early
+
+use std::fmt;
+
+use crate::models::elevanlabs_models::{
+    DEPRECATED_MODELS, ELEVEN_MULTILINGUAL_TTV_V2, ELEVEN_MULTILINGUAL_V2, ELEVEN_TTV_V3,
+};
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, Model, ParameterViolation};
+
+/// Models the crate targets, which are not necessarily listed by GET /v1/models
+/// (it lists Text-to-Speech models)
+const KNOWN_MODELS: [&str; 3] = [
+    ELEVEN_MULTILINGUAL_TTV_V2,
+    ELEVEN_TTV_V3,
+    ELEVEN_MULTILINGUAL_V2,
+];
+
+/// Whether a model ID can still be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelStatus {
+    Available,
+    /// Still served, but scheduled for removal (see `DEPRECATED_MODELS`)
+    Deprecated,
+    /// Neither listed by the API nor known to the crate
+    Retired,
+}
+
+impl ModelStatus {
+    /// Status of `model_id` given the models listed by `list_models`
+    pub fn of(model_id: &str, models: &[Model]) -> Self {
+        if DEPRECATED_MODELS.contains(&model_id) {
+            ModelStatus::Deprecated
+        } else if KNOWN_MODELS.contains(&model_id)
+            || models.iter().any(|model| model.model_id == model_id)
+        {
+            ModelStatus::Available
+        } else {
+            ModelStatus::Retired
+        }
+    }
+}
+
+impl fmt::Display for ModelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ModelStatus::Available => "available",
+            ModelStatus::Deprecated => "deprecated",
+            ModelStatus::Retired => "retired",
+        })
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// List the models available to the account (GET /v1/models)
+    pub async fn list_models(&self) -> Result<Vec<Model>, ElevenLabsTTVError> {
+        let url = self.url(&["models"])?;
+
+        self.send_json("models", self.inner.client.get(url)).await
+    }
+
+    /// Check the model of a request against `list_models` before sending it
+    ///
+    /// Deprecated models are logged as a warning and reported as `ModelStatus::Deprecated`;
+    /// retired ones fail with a `ValidationError`. Requests without a model use the API default
+    /// and are always available.
+    pub async fn check_model(
+        &self,
+        model_id: Option<&str>,
+    ) -> Result<ModelStatus, ElevenLabsTTVError> {
+        let Some(model_id) = model_id else {
+            return Ok(ModelStatus::Available);
+        };
+
+        let status = ModelStatus::of(model_id, &self.list_models().await?);
+        match status {
+            ModelStatus::Available => {}
+            ModelStatus::Deprecated => crate::logging::model_deprecated(model_id),
+            ModelStatus::Retired => crate::validation::into_result(vec![ParameterViolation {
+                field: "model_id",
+                value: model_id.to_string(),
+                allowed: "a model listed by GET /v1/models".to_string(),
+            }])?,
+        }
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_status() {
+        let models: Vec<Model> = serde_json::from_value(serde_json::json!([
+            { "model_id": "eleven_flash_v2_5", "languages": [{ "language_id": "en" }] },
+            { "model_id": "eleven_monolingual_v1" }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            ModelStatus::of("eleven_flash_v2_5", &models),
+            ModelStatus::Available
+        );
+        assert_eq!(
+            ModelStatus::of(ELEVEN_TTV_V3, &models),
+            ModelStatus::Available
+        );
+        assert_eq!(
+            ModelStatus::of("eleven_monolingual_v1", &models),
+            ModelStatus::Deprecated
+        );
+        assert_eq!(
+            ModelStatus::of("eleven_ttv_v1", &models),
+            ModelStatus::Retired
+        );
+    }
+}
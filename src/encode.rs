@@ -0,0 +1,98 @@
+//! Transcoding of PCM audio to FLAC and OGG Vorbis for archival (`encode` feature)
+
+use std::num::{NonZeroU8, NonZeroU32};
+
+use flacenc::component::BitRepr;
+use flacenc::error::Verify;
+use vorbis_rs::VorbisEncoderBuilder;
+
+use crate::{ElevenLabsTTVError, PcmAudio};
+
+impl PcmAudio {
+    /// Encode the audio as a FLAC file (lossless)
+    pub fn to_flac(&self) -> Result<Vec<u8>, ElevenLabsTTVError> {
+        let config = flacenc::config::Encoder::default()
+            .into_verified()
+            .map_err(|(_, e)| encode_error(e))?;
+        let samples: Vec<i32> = self.samples.iter().map(|&s| i32::from(s)).collect();
+        let source = flacenc::source::MemSource::from_samples(
+            &samples,
+            self.channels as usize,
+            16,
+            self.sample_rate as usize,
+        );
+
+        let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+            .map_err(encode_error)?;
+        let mut sink = flacenc::bitsink::ByteSink::new();
+        stream.write(&mut sink).map_err(encode_error)?;
+
+        Ok(sink.into_inner())
+    }
+
+    /// Encode the audio as an OGG Vorbis file (lossy, default quality)
+    pub fn to_ogg_vorbis(&self) -> Result<Vec<u8>, ElevenLabsTTVError> {
+        let sample_rate = NonZeroU32::new(self.sample_rate)
+            .ok_or_else(|| ElevenLabsTTVError::AudioEncodeError("sample rate is 0".to_string()))?;
+        let channels = u8::try_from(self.channels)
+            .ok()
+            .and_then(NonZeroU8::new)
+            .ok_or_else(|| {
+                ElevenLabsTTVError::AudioEncodeError(format!(
+                    "unsupported channel count {}",
+                    self.channels
+                ))
+            })?;
+
+        let mut encoder = VorbisEncoderBuilder::new(sample_rate, channels, Vec::new())
+            .map_err(encode_error)?
+            .build()
+            .map_err(encode_error)?;
+
+        // Vorbis takes planar f32 samples, one slice per channel
+        let channels = self.channels as usize;
+        let mut planar = vec![Vec::with_capacity(self.frames()); channels];
+        for frame in self.samples.chunks_exact(channels) {
+            for (channel, &sample) in frame.iter().enumerate() {
+                planar[channel].push(f32::from(sample) / 32768.0);
+            }
+        }
+        for block in 0..self.frames().div_ceil(4096) {
+            let range = block * 4096..((block + 1) * 4096).min(self.frames());
+            let block: Vec<&[f32]> = planar.iter().map(|c| &c[range.clone()]).collect();
+            encoder.encode_audio_block(&block).map_err(encode_error)?;
+        }
+
+        encoder.finish().map_err(encode_error)
+    }
+}
+
+fn encode_error<E: std::fmt::Display>(error: E) -> ElevenLabsTTVError {
+    ElevenLabsTTVError::AudioEncodeError(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine() -> PcmAudio {
+        PcmAudio {
+            sample_rate: 24000,
+            channels: 1,
+            samples: (0..24000)
+                .map(|i| {
+                    ((i as f32 * 440.0 * std::f32::consts::TAU / 24000.0).sin() * 8000.0) as i16
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_transcode() {
+        let flac = sine().to_flac().unwrap();
+        assert_eq!(&flac[..4], b"fLaC");
+
+        let ogg = sine().to_ogg_vorbis().unwrap();
+        assert_eq!(&ogg[..4], b"OggS");
+    }
+}
@@ -1,16 +1,77 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+/// Longest response body kept in a `ParseError`, in bytes
+pub const MAX_PARSE_ERROR_BODY_LEN: usize = 1024;
+
+/// The API call an error came from, so logs say which endpoint failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// HTTP method, e.g. "POST"
+    pub method: String,
+    /// Path template used to label metrics, e.g. "text-to-voice/{generated_voice_id}/stream"
+    pub endpoint: &'static str,
+    /// Actual URL path, e.g. "/v1/text-to-voice/gen-1/stream"
+    pub path: String,
+    /// Query string with secret-looking values masked (see `redact::redact_query`)
+    pub query: Option<String>,
+}
+
+impl RequestContext {
+    pub(crate) fn new(
+        endpoint: &'static str,
+        method: &reqwest::Method,
+        url: &reqwest::Url,
+    ) -> Self {
+        Self {
+            method: method.to_string(),
+            endpoint,
+            path: url.path().to_string(),
+            query: url.query().map(crate::redact::redact_query),
+        }
+    }
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        if let Some(query) = &self.query {
+            write!(f, "?{}", query)?;
+        }
+        Ok(())
+    }
+}
+
 /// All possible errors that can occur when using the ElevenLabs API
+///
+/// New variants may be added (e.g. for new error classes returned by the API) in minor
+/// releases, so matches need a wildcard arm. `kind()` and `to_error_report()` cover every
+/// variant, and make a reasonable fallback for the ones a match does not handle.
 #[derive(Debug)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Error), uniffi(flat_error))]
+#[non_exhaustive]
 pub enum ElevenLabsTTVError {
     /// HTTP request failed (network issues, timeout, etc.)
-    RequestError(reqwest::Error),
+    RequestError {
+        source: reqwest::Error,
+        context: Option<RequestContext>,
+    },
 
     /// API returned an error status code
-    ApiError { status: u16, message: String },
+    ApiError {
+        status: u16,
+        message: String,
+        context: Option<RequestContext>,
+    },
 
     /// Failed to parse JSON response
-    ParseError(reqwest::Error),
+    ParseError {
+        source: serde_json::Error,
+        /// Start of the raw response body, at most `MAX_PARSE_ERROR_BODY_LEN` bytes
+        body: String,
+        context: Option<RequestContext>,
+    },
 
     /// Invalid API key or authentication failed
     AuthenticationError(String),
@@ -21,21 +82,238 @@ pub enum ElevenLabsTTVError {
         message: String,
     },
 
+    /// The circuit breaker is open after repeated upstream failures, see
+    /// `ElevenLabsTTVClientBuilder::circuit_breaker`. The request was not sent
+    CircuitOpenError {
+        /// Seconds until a probe request is let through, `None` while one is in flight
+        retry_after: Option<u64>,
+    },
+
     /// Quota exceeded (not enough credits)
     QuotaExceededError(String),
 
+    /// Not enough quota left for a planned run, see `ElevenLabsTTVClient::ensure_quota`
+    InsufficientQuotaError { required: u64, remaining: u64 },
+
     /// Invalid input parameters
     ValidationError(String),
+
+    /// Reading or writing a local file failed
+    IoError(std::io::Error),
+
+    /// Failed to serialize or deserialize local data (e.g. a saved session)
+    SerializationError(serde_json::Error),
+
+    /// Invalid configuration file (TOML, JSON or YAML)
+    ConfigError(String),
+
+    /// Audio returned by the API could not be decoded (e.g. invalid base64)
+    AudioDecodeError(String),
+
+    /// Audio could not be encoded (e.g. transcoding to FLAC or OGG Vorbis)
+    AudioEncodeError(String),
+
+    /// A `reqwest_middleware` middleware failed (`middleware` feature)
+    MiddlewareError(String),
+
+    /// A custom `HttpBackend` failed to execute a request
+    BackendError(String),
+
+    /// A long-running operation did not finish in time, e.g. `wait_for_speaker_separation`
+    TimeoutError(String),
+}
+
+impl ElevenLabsTTVError {
+    /// The API call that failed, for request, API and parse errors raised while sending it
+    pub fn context(&self) -> Option<&RequestContext> {
+        match self {
+            ElevenLabsTTVError::RequestError { context, .. }
+            | ElevenLabsTTVError::ApiError { context, .. }
+            | ElevenLabsTTVError::ParseError { context, .. } => context.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// A `ParseError` for a response `body` that failed to deserialize, keeping (the start of)
+    /// the body so schema drift can be diagnosed from logs
+    pub(crate) fn parse_error(
+        source: serde_json::Error,
+        body: &[u8],
+        context: Option<RequestContext>,
+    ) -> Self {
+        let mut body = String::from_utf8_lossy(body).into_owned();
+        if body.len() > MAX_PARSE_ERROR_BODY_LEN {
+            let mut end = MAX_PARSE_ERROR_BODY_LEN;
+            while !body.is_char_boundary(end) {
+                end -= 1;
+            }
+            body.truncate(end);
+            body.push_str("...");
+        }
+
+        ElevenLabsTTVError::ParseError {
+            source,
+            body,
+            context,
+        }
+    }
+
+    /// Attach the API call to a request, API or parse error that does not have one yet
+    ///
+    /// The context replaces the URL of `reqwest` errors, so their query string is only
+    /// shown redacted.
+    pub(crate) fn with_context(self, request_context: &RequestContext) -> Self {
+        match self {
+            ElevenLabsTTVError::RequestError {
+                source,
+                context: None,
+            } => ElevenLabsTTVError::RequestError {
+                source: source.without_url(),
+                context: Some(request_context.clone()),
+            },
+            ElevenLabsTTVError::ParseError {
+                source,
+                body,
+                context: None,
+            } => ElevenLabsTTVError::ParseError {
+                source,
+                body,
+                context: Some(request_context.clone()),
+            },
+            ElevenLabsTTVError::ApiError {
+                status,
+                message,
+                context: None,
+            } => ElevenLabsTTVError::ApiError {
+                status,
+                message,
+                context: Some(request_context.clone()),
+            },
+            error => error,
+        }
+    }
+}
+
+/// Serializable summary of an `ElevenLabsTTVError`, e.g. to send it over a job queue or RPC
+/// boundary
+///
+/// `ElevenLabsTTVError` itself serializes as its report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    /// Snake-case variant name, e.g. "api_error" or "rate_limit_error"
+    pub kind: String,
+    /// The error's `Display` message
+    pub message: String,
+    /// HTTP status of an `ApiError`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Seconds to wait before retrying, for a `RateLimitError`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+    /// Path template of the failed API call, e.g. "text-to-voice/design"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// Method, path and redacted query of the failed API call (`RequestContext`'s `Display`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<String>,
+}
+
+impl fmt::Display for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ErrorReport {}
+
+impl ElevenLabsTTVError {
+    /// Snake-case name of the variant, e.g. "api_error"
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ElevenLabsTTVError::RequestError { .. } => "request_error",
+            ElevenLabsTTVError::ApiError { .. } => "api_error",
+            ElevenLabsTTVError::ParseError { .. } => "parse_error",
+            ElevenLabsTTVError::AuthenticationError(_) => "authentication_error",
+            ElevenLabsTTVError::RateLimitError { .. } => "rate_limit_error",
+            ElevenLabsTTVError::CircuitOpenError { .. } => "circuit_open_error",
+            ElevenLabsTTVError::QuotaExceededError(_) => "quota_exceeded_error",
+            ElevenLabsTTVError::InsufficientQuotaError { .. } => "insufficient_quota_error",
+            ElevenLabsTTVError::ValidationError(_) => "validation_error",
+            ElevenLabsTTVError::IoError(_) => "io_error",
+            ElevenLabsTTVError::SerializationError(_) => "serialization_error",
+            ElevenLabsTTVError::ConfigError(_) => "config_error",
+            ElevenLabsTTVError::AudioDecodeError(_) => "audio_decode_error",
+            ElevenLabsTTVError::AudioEncodeError(_) => "audio_encode_error",
+            ElevenLabsTTVError::MiddlewareError(_) => "middleware_error",
+            ElevenLabsTTVError::BackendError(_) => "backend_error",
+            ElevenLabsTTVError::TimeoutError(_) => "timeout_error",
+        }
+    }
+
+    /// Serializable summary of the error
+    pub fn to_error_report(&self) -> ErrorReport {
+        ErrorReport {
+            kind: self.kind().to_string(),
+            message: self.to_string(),
+            status: match self {
+                ElevenLabsTTVError::ApiError { status, .. } => Some(*status),
+                _ => None,
+            },
+            retry_after: match self {
+                ElevenLabsTTVError::RateLimitError { retry_after, .. }
+                | ElevenLabsTTVError::CircuitOpenError { retry_after } => *retry_after,
+                _ => None,
+            },
+            endpoint: self.context().map(|context| context.endpoint.to_string()),
+            request: self.context().map(RequestContext::to_string),
+        }
+    }
+}
+
+impl Serialize for ElevenLabsTTVError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_error_report().serialize(serializer)
+    }
+}
+
+/// " on POST /v1/...", or nothing without a context
+struct On<'a>(&'a Option<RequestContext>);
+
+impl fmt::Display for On<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(context) => write!(f, " on {}", context),
+            None => Ok(()),
+        }
+    }
 }
 
 impl fmt::Display for ElevenLabsTTVError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ElevenLabsTTVError::RequestError(e) => write!(f, "Request failed: {}", e),
-            ElevenLabsTTVError::ApiError { status, message } => {
-                write!(f, "API error ({}): {}", status, message)
+            ElevenLabsTTVError::RequestError { source, context } => {
+                write!(f, "Request failed{}: {}", On(context), source)
+            }
+            ElevenLabsTTVError::ApiError {
+                status,
+                message,
+                context,
+            } => {
+                write!(f, "API error ({}){}: {}", status, On(context), message)
+            }
+            ElevenLabsTTVError::ParseError {
+                source,
+                body,
+                context,
+            } => {
+                write!(
+                    f,
+                    "Failed to parse response{}: {} (body: {})",
+                    On(context),
+                    source,
+                    body
+                )
             }
-            ElevenLabsTTVError::ParseError(e) => write!(f, "Failed to parse response: {}", e),
             ElevenLabsTTVError::AuthenticationError(msg) => {
                 write!(f, "Authentication failed: {}", msg)
             }
@@ -50,8 +328,39 @@ impl fmt::Display for ElevenLabsTTVError {
                 ),
                 None => write!(f, "Rate limit exceeded: {}", message),
             },
+            ElevenLabsTTVError::CircuitOpenError { retry_after } => match retry_after {
+                Some(seconds) => write!(
+                    f,
+                    "Circuit breaker open after repeated upstream failures (retry in {}s)",
+                    seconds
+                ),
+                None => write!(
+                    f,
+                    "Circuit breaker open after repeated upstream failures (probe in flight)"
+                ),
+            },
             ElevenLabsTTVError::QuotaExceededError(msg) => write!(f, "Quota exceeded: {}", msg),
+            ElevenLabsTTVError::InsufficientQuotaError {
+                required,
+                remaining,
+            } => write!(
+                f,
+                "Insufficient quota: {} characters required, {} remaining",
+                required, remaining
+            ),
             ElevenLabsTTVError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            ElevenLabsTTVError::IoError(e) => write!(f, "I/O error: {}", e),
+            ElevenLabsTTVError::SerializationError(e) => write!(f, "Serialization error: {}", e),
+            ElevenLabsTTVError::ConfigError(msg) => write!(f, "Invalid configuration: {}", msg),
+            ElevenLabsTTVError::AudioDecodeError(msg) => {
+                write!(f, "Failed to decode audio: {}", msg)
+            }
+            ElevenLabsTTVError::AudioEncodeError(msg) => {
+                write!(f, "Failed to encode audio: {}", msg)
+            }
+            ElevenLabsTTVError::MiddlewareError(msg) => write!(f, "Middleware error: {}", msg),
+            ElevenLabsTTVError::BackendError(msg) => write!(f, "HTTP backend error: {}", msg),
+            ElevenLabsTTVError::TimeoutError(msg) => write!(f, "Timed out: {}", msg),
         }
     }
 }
@@ -59,8 +368,10 @@ impl fmt::Display for ElevenLabsTTVError {
 impl std::error::Error for ElevenLabsTTVError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            ElevenLabsTTVError::RequestError(e) => Some(e),
-            ElevenLabsTTVError::ParseError(e) => Some(e),
+            ElevenLabsTTVError::RequestError { source, .. } => Some(source),
+            ElevenLabsTTVError::ParseError { source, .. } => Some(source),
+            ElevenLabsTTVError::IoError(e) => Some(e),
+            ElevenLabsTTVError::SerializationError(e) => Some(e),
             _ => None,
         }
     }
@@ -84,10 +395,71 @@ impl From<reqwest::Error> for ElevenLabsTTVError {
                 _ => ElevenLabsTTVError::ApiError {
                     status: status_code,
                     message: error.to_string(),
+                    context: None,
                 },
             }
         } else {
-            ElevenLabsTTVError::RequestError(error)
+            ElevenLabsTTVError::RequestError {
+                source: error,
+                context: None,
+            }
         }
     }
 }
+
+impl From<std::io::Error> for ElevenLabsTTVError {
+    fn from(error: std::io::Error) -> Self {
+        ElevenLabsTTVError::IoError(error)
+    }
+}
+
+impl From<serde_json::Error> for ElevenLabsTTVError {
+    fn from(error: serde_json::Error) -> Self {
+        ElevenLabsTTVError::SerializationError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_truncates_body() {
+        let body = format!(
+            "{{\"previews\": \"{}\"}}",
+            "é".repeat(MAX_PARSE_ERROR_BODY_LEN)
+        );
+        let source = serde_json::from_str::<u32>(&body).unwrap_err();
+
+        let error = ElevenLabsTTVError::parse_error(source, body.as_bytes(), None);
+        let ElevenLabsTTVError::ParseError { body, .. } = &error else {
+            panic!("expected a ParseError");
+        };
+        assert!(body.starts_with("{\"previews\": \"éé"));
+        assert!(body.ends_with("..."));
+        assert!(body.len() <= MAX_PARSE_ERROR_BODY_LEN + 3);
+    }
+
+    #[test]
+    fn test_error_report_round_trip() {
+        let error = ElevenLabsTTVError::ApiError {
+            status: 422,
+            message: "invalid seed".to_string(),
+            context: Some(RequestContext {
+                method: "POST".to_string(),
+                endpoint: "text-to-voice/design",
+                path: "/v1/text-to-voice/design".to_string(),
+                query: None,
+            }),
+        };
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["kind"], "api_error");
+        assert_eq!(json["status"], 422);
+        assert_eq!(json["request"], "POST /v1/text-to-voice/design");
+
+        let report: ErrorReport = serde_json::from_value(json).unwrap();
+        assert_eq!(report, error.to_error_report());
+        assert_eq!(report.to_string(), error.to_string());
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use reqwest::StatusCode;
+use reqwest::Url;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use serde::de::DeserializeOwned;
+
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, RequestContext};
+
+/// Maximum number of cached responses; the cache is cleared when it is full
+const MAX_ENTRIES: usize = 32;
+
+/// Last response body and ETag per URL, for conditional GET requests
+#[derive(Debug, Default)]
+pub(crate) struct EtagCache {
+    entries: Mutex<HashMap<String, (String, Bytes)>>,
+}
+
+impl EtagCache {
+    fn get(&self, url: &str) -> Option<(String, Bytes)> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    fn insert(&self, url: String, etag: String, body: Bytes) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= MAX_ENTRIES && !entries.contains_key(&url) {
+            entries.clear();
+        }
+        entries.insert(url, (etag, body));
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// GET a JSON resource, sending `If-None-Match` with the ETag of the previous response
+    /// and reusing its body when the API answers 304 Not Modified
+    pub(crate) async fn send_cached_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        url: Url,
+    ) -> Result<T, ElevenLabsTTVError> {
+        let Some(cache) = &self.inner.etag_cache else {
+            return self.send_json(endpoint, self.inner.client.get(url)).await;
+        };
+
+        let key = url.to_string();
+        let cached = cache.get(&key);
+        let mut request = self.inner.client.get(url);
+        if let Some((etag, _)) = &cached {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let response = self.send(endpoint, request).await?;
+        let context = response.extensions().get::<RequestContext>().cloned();
+        let body = match cached {
+            Some((_, body)) if response.status() == StatusCode::NOT_MODIFIED => body,
+            _ => {
+                let etag = response
+                    .headers()
+                    .get(ETAG)
+                    .and_then(|etag| etag.to_str().ok())
+                    .map(str::to_string);
+                let body = crate::read_body(response, context.as_ref()).await?;
+                if let Some(etag) = etag {
+                    cache.insert(key, etag, body.clone());
+                }
+                body
+            }
+        };
+
+        crate::parse_body(&body, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_is_bounded() {
+        let cache = EtagCache::default();
+        for i in 0..MAX_ENTRIES {
+            cache.insert(format!("url-{}", i), "etag".to_string(), Bytes::new());
+        }
+        assert!(cache.get("url-0").is_some());
+
+        cache.insert("url-new".to_string(), "etag".to_string(), Bytes::new());
+        assert!(cache.get("url-0").is_none());
+        assert!(cache.get("url-new").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cached_json_parse_error_names_the_endpoint() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_string("{\"voice_id\": 42}"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .etag_cache(true)
+            .build()
+            .unwrap();
+        let error = client.get_voice("voice-1").await.unwrap_err();
+
+        assert!(
+            matches!(&error, ElevenLabsTTVError::ParseError { body, .. } if body.contains("42")),
+            "{:?}",
+            error
+        );
+        assert_eq!(error.context().unwrap().endpoint, "voices/{voice_id}");
+        assert!(
+            error.to_string().contains("/v1/voices/voice-1"),
+            "{}",
+            error
+        );
+    }
+}
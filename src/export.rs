@@ -0,0 +1,441 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DesignSession, ElevenLabsTTVError, LanguageCode, MediaType, TTVDesignVoiceRequest,
+    TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview,
+};
+
+/// Metadata of design previews, for tracking candidates across many runs
+///
+/// Each entry is flat, so manifests of several runs can be concatenated (`extend`) and
+/// written as a single JSON array or CSV table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PreviewManifest {
+    pub entries: Vec<PreviewManifestEntry>,
+}
+
+/// One preview of a `PreviewManifest`, with the request parameters used when known
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreviewManifestEntry {
+    pub generated_voice_id: String,
+    pub language: Option<LanguageCode>,
+    pub duration_secs: f64,
+    pub media_type: MediaType,
+    pub voice_description: Option<String>,
+    pub model_id: Option<String>,
+    pub output_format: Option<String>,
+    pub seed: Option<u32>,
+    pub guidance_scale: Option<u32>,
+    pub quality: Option<f32>,
+    pub loudness: Option<f32>,
+    pub prompt_strength: Option<f32>,
+}
+
+/// CSV header, in `PreviewManifestEntry` field order
+const CSV_HEADER: &str = "generated_voice_id,language,duration_secs,media_type,voice_description,\
+model_id,output_format,seed,guidance_scale,quality,loudness,prompt_strength";
+
+impl PreviewManifest {
+    /// Entries for `previews`, with the parameters of `request` if known
+    pub fn new(
+        previews: &[TTVDesignVoiceResponseVoicePreview],
+        request: Option<&TTVDesignVoiceRequest>,
+    ) -> Self {
+        Self {
+            entries: previews
+                .iter()
+                .map(|preview| PreviewManifestEntry {
+                    generated_voice_id: preview.generated_voice_id.clone(),
+                    language: preview.language.clone(),
+                    duration_secs: preview.duration_secs,
+                    media_type: preview.media_type.clone(),
+                    voice_description: request.map(|r| r.voice_description.clone()),
+                    model_id: request.and_then(|r| r.model_id.clone()),
+                    output_format: request.and_then(|r| r.output_format.clone()),
+                    seed: request.and_then(|r| r.seed),
+                    guidance_scale: request.and_then(|r| r.guidance_scale),
+                    quality: request.and_then(|r| r.quality),
+                    loudness: request.and_then(|r| r.loudness),
+                    prompt_strength: request.and_then(|r| r.prompt_strength),
+                })
+                .collect(),
+        }
+    }
+
+    /// Append the entries of another manifest, e.g. of another design run
+    pub fn extend(&mut self, other: PreviewManifest) {
+        self.entries.extend(other.entries);
+    }
+
+    /// Find the entry of a generated voice ID
+    pub fn entry(&self, generated_voice_id: &str) -> Option<&PreviewManifestEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.generated_voice_id == generated_voice_id)
+    }
+
+    /// Write the manifest as a pretty-printed JSON array
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<(), ElevenLabsTTVError> {
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Write the manifest as CSV with a header row. Unknown values are empty cells.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<(), ElevenLabsTTVError> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for entry in &self.entries {
+            let cells = [
+                csv_cell(&entry.generated_voice_id),
+                entry
+                    .language
+                    .as_ref()
+                    .map(|language| csv_cell(language.code()))
+                    .unwrap_or_default(),
+                entry.duration_secs.to_string(),
+                csv_cell(entry.media_type.as_str()),
+                entry
+                    .voice_description
+                    .as_deref()
+                    .map(csv_cell)
+                    .unwrap_or_default(),
+                entry.model_id.as_deref().map(csv_cell).unwrap_or_default(),
+                entry
+                    .output_format
+                    .as_deref()
+                    .map(csv_cell)
+                    .unwrap_or_default(),
+                optional_cell(entry.seed),
+                optional_cell(entry.guidance_scale),
+                optional_cell(entry.quality),
+                optional_cell(entry.loudness),
+                optional_cell(entry.prompt_strength),
+            ];
+            writeln!(writer, "{}", cells.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TTVDesignVoiceResponse {
+    /// Metadata of every preview. The request parameters are unknown to the response, see
+    /// `DesignSession::to_manifest` or `PreviewManifest::new` to include them.
+    pub fn to_manifest(&self) -> PreviewManifest {
+        PreviewManifest::new(&self.previews, None)
+    }
+}
+
+impl DesignSession {
+    /// Metadata of every preview, with the request parameters used
+    pub fn to_manifest(&self) -> PreviewManifest {
+        PreviewManifest::new(&self.previews, Some(&self.request))
+    }
+}
+
+/// Quote a CSV cell if it contains a separator, quote or line break
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn optional_cell<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+impl TTVDesignVoiceResponse {
+    /// Write every preview into `dir` (created if missing), e.g. to audition them in an
+    /// external player. Returns the written paths, in preview order.
+    ///
+    /// pattern: File name with placeholders `{index}` (1-based), `{generated_voice_id}`,
+    /// `{language}` and `{ext}` (from the media type), e.g. "voice_{index}_{generated_voice_id}.{ext}".
+    ///
+    /// Previews generated with `stream_previews(true)` carry no audio; download them with
+    /// `fetch_all_previews` instead.
+    pub fn save_all<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        pattern: &str,
+    ) -> Result<Vec<PathBuf>, ElevenLabsTTVError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let mut paths = Vec::with_capacity(self.previews.len());
+        for (index, preview) in self.previews.iter().enumerate() {
+            if preview.audio_base_64.is_empty() {
+                return Err(ElevenLabsTTVError::ValidationError(format!(
+                    "preview {} has no inline audio (streamed preview)",
+                    preview.generated_voice_id
+                )));
+            }
+
+            let path = dir.join(file_name(pattern, index, preview));
+            fs::write(&path, preview.decode_audio()?)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// File name pattern of previews in `DesignSession::export_zip` archives
+#[cfg(feature = "zip")]
+const ZIP_PREVIEW_PATTERN: &str = "previews/voice_{index}_{generated_voice_id}.{ext}";
+
+#[cfg(feature = "zip")]
+#[derive(Serialize)]
+struct ZipManifest<'a> {
+    voice_description: &'a str,
+    text: &'a str,
+    /// The request parameters, without reference audio
+    parameters: TTVDesignVoiceRequest,
+    previews: Vec<ZipManifestPreview<'a>>,
+}
+
+#[cfg(feature = "zip")]
+#[derive(Serialize)]
+struct ZipManifestPreview<'a> {
+    /// Path of the audio in the archive, `None` for streamed previews
+    file: Option<String>,
+    generated_voice_id: &'a str,
+    media_type: &'a MediaType,
+    duration_secs: f64,
+    language: Option<&'a LanguageCode>,
+}
+
+#[cfg(feature = "zip")]
+impl DesignSession {
+    /// Bundle every preview and a `manifest.json` (description, parameters, generated voice
+    /// IDs, durations) into a zip archive at `path`, e.g. to hand a design run to a voice
+    /// director (`zip` feature)
+    ///
+    /// Previews are stored under `previews/`; streamed previews, which carry no audio, are
+    /// listed in the manifest only.
+    pub fn export_zip<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTVError> {
+        use zip::write::SimpleFileOptions;
+
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        let mut archive = zip::ZipWriter::new(fs::File::create(path)?);
+
+        let mut previews = Vec::with_capacity(self.previews.len());
+        for (index, preview) in self.previews.iter().enumerate() {
+            let file = if preview.audio_base_64.is_empty() {
+                None
+            } else {
+                let file = file_name(ZIP_PREVIEW_PATTERN, index, preview);
+                archive
+                    .start_file(file.as_str(), options)
+                    .map_err(zip_error)?;
+                archive.write_all(&preview.decode_audio()?)?;
+                Some(file)
+            };
+            previews.push(ZipManifestPreview {
+                file,
+                generated_voice_id: &preview.generated_voice_id,
+                media_type: &preview.media_type,
+                duration_secs: preview.duration_secs,
+                language: preview.language.as_ref(),
+            });
+        }
+
+        let mut parameters = self.request.clone();
+        parameters.reference_audio_base64 = None;
+        parameters.reference_audios.clear();
+        let manifest = ZipManifest {
+            voice_description: &self.request.voice_description,
+            text: &self.text,
+            parameters,
+            previews,
+        };
+        archive
+            .start_file("manifest.json", options)
+            .map_err(zip_error)?;
+        serde_json::to_writer_pretty(&mut archive, &manifest)?;
+        archive.finish().map_err(zip_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zip")]
+fn zip_error(error: zip::result::ZipError) -> ElevenLabsTTVError {
+    match error {
+        zip::result::ZipError::Io(error) => ElevenLabsTTVError::IoError(error),
+        error => ElevenLabsTTVError::IoError(std::io::Error::other(error)),
+    }
+}
+
+/// Expand the placeholders of a `save_all` file name pattern
+///
+/// The generated voice ID and language come from the API, so they are made path-safe
+/// (see `path_safe`) before being substituted: they cannot add directories or leave `dir`.
+pub(crate) fn file_name(
+    pattern: &str,
+    index: usize,
+    preview: &TTVDesignVoiceResponseVoicePreview,
+) -> String {
+    pattern
+        .replace("{index}", &(index + 1).to_string())
+        .replace(
+            "{generated_voice_id}",
+            &path_safe(&preview.generated_voice_id),
+        )
+        .replace(
+            "{language}",
+            &path_safe(
+                preview
+                    .language
+                    .as_ref()
+                    .map_or("unknown", LanguageCode::code),
+            ),
+        )
+        .replace("{ext}", preview.media_type.file_extension())
+}
+
+/// Replace path separators, drive separators, control characters and `..` with `_`
+fn path_safe(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect::<String>()
+        .replace("..", "__")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_all() {
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-1")
+                .audio_base_64("aGVsbG8="),
+            TTVDesignVoiceResponseVoicePreview {
+                language: None,
+                ..TTVDesignVoiceResponseVoicePreview::fixture()
+                    .generated_voice_id("gen-2")
+                    .audio_base_64("d29ybGQ=")
+                    .media_type("audio/wav")
+            },
+        ]);
+        let dir = std::env::temp_dir().join(format!("ttv-save-all-{}", std::process::id()));
+
+        let paths = response
+            .save_all(&dir, "voice_{index}_{generated_voice_id}_{language}.{ext}")
+            .unwrap();
+        assert_eq!(paths[0], dir.join("voice_1_gen-1_en.mp3"));
+        assert_eq!(paths[1], dir.join("voice_2_gen-2_unknown.wav"));
+        assert_eq!(fs::read(&paths[1]).unwrap(), b"world");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_name_keeps_server_values_inside_dir() {
+        let mut preview = TTVDesignVoiceResponseVoicePreview::fixture()
+            .generated_voice_id("../../etc/evil")
+            .language("..\\C:x");
+
+        assert_eq!(
+            file_name("{generated_voice_id}/{language}.{ext}", 0, &preview),
+            "______etc_evil/___C_x.mp3"
+        );
+
+        preview.generated_voice_id = "..".to_string();
+        let name = file_name("{generated_voice_id}", 0, &preview);
+        assert_eq!(name, "__");
+        assert_eq!(
+            Path::new("previews").join(name).parent(),
+            Some(Path::new("previews"))
+        );
+    }
+
+    #[test]
+    fn test_manifest_csv() {
+        let client = crate::ElevenLabsTTVClient::new("test-key");
+        let request = client
+            .design_voice("Warm, raspy \"blues\" singer, 60s")
+            .seed(7)
+            .build()
+            .unwrap();
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-1")
+                .duration_secs(2.5),
+        ]);
+
+        assert_eq!(response.to_manifest().entries[0].voice_description, None);
+        let manifest = DesignSession::new(request, response).to_manifest();
+        assert_eq!(manifest.entry("gen-1").unwrap().seed, Some(7));
+
+        let mut csv = Vec::new();
+        manifest.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert_eq!(
+            row,
+            "gen-1,en,2.5,audio/mpeg,\"Warm, raspy \"\"blues\"\" singer, 60s\",\
+             eleven_multilingual_ttv_v2,,7,5,,0.5,"
+        );
+        assert_eq!(csv.lines().next().unwrap().split(',').count(), 12);
+    }
+
+    #[cfg(feature = "zip")]
+    #[test]
+    fn test_export_zip() {
+        use std::io::Read;
+
+        let client = crate::ElevenLabsTTVClient::new("test-key");
+        let request = client
+            .design_voice("Husky late-night jazz singer")
+            .seed(7)
+            .build()
+            .unwrap();
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-1")
+                .audio_base_64("aGVsbG8=")
+                .duration_secs(2.5),
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-2")
+                .audio_base_64("")
+                .duration_secs(3.0),
+        ]);
+        let path = std::env::temp_dir().join(format!("ttv-export-{}.zip", std::process::id()));
+
+        DesignSession::new(request, response)
+            .export_zip(&path)
+            .unwrap();
+        let mut archive = zip::ZipArchive::new(fs::File::open(&path).unwrap()).unwrap();
+        let mut audio = String::new();
+        archive
+            .by_name("previews/voice_1_gen-1.mp3")
+            .unwrap()
+            .read_to_string(&mut audio)
+            .unwrap();
+        assert_eq!(audio, "hello");
+        let manifest: serde_json::Value =
+            serde_json::from_reader(archive.by_name("manifest.json").unwrap()).unwrap();
+        assert_eq!(
+            manifest["voice_description"],
+            "Husky late-night jazz singer"
+        );
+        assert_eq!(manifest["parameters"]["seed"], 7);
+        assert_eq!(manifest["previews"][0]["duration_secs"], 2.5);
+        assert!(manifest["previews"][1]["file"].is_null());
+
+        fs::remove_file(path).unwrap();
+    }
+}
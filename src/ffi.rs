@@ -0,0 +1,350 @@
+//! Minimal C interface (`ffi` feature), for DAW and game-engine plugins written in C/C++
+//!
+//! Build a shared or static library with
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`) and declare:
+//!
+//! ```c
+//! typedef struct ElevenLabsTTVFfiClient ElevenLabsTTVFfiClient;
+//!
+//! ElevenLabsTTVFfiClient *elevenlabs_ttv_client_new(const char *api_key);
+//! void elevenlabs_ttv_client_free(ElevenLabsTTVFfiClient *client);
+//! char *elevenlabs_ttv_design(const ElevenLabsTTVFfiClient *client, const char *request_json);
+//! char *elevenlabs_ttv_create(const ElevenLabsTTVFfiClient *client, const char *request_json);
+//! int elevenlabs_ttv_fetch_preview(const ElevenLabsTTVFfiClient *client,
+//!                                  const char *generated_voice_id,
+//!                                  uint8_t **audio, size_t *audio_len);
+//! const char *elevenlabs_ttv_last_error(void);
+//! void elevenlabs_ttv_string_free(char *string);
+//! void elevenlabs_ttv_bytes_free(uint8_t *audio, size_t audio_len);
+//! ```
+//!
+//! Requests and responses are the API's JSON bodies. Calls block until the request completes.
+//! On failure, functions return NULL (or -1) and `elevenlabs_ttv_last_error` describes the
+//! error until the next failing call on the same thread. Panics are caught at the boundary
+//! and reported the same way, instead of unwinding into C.
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, TTVCreateVoiceRequest, TTVDesignVoiceRequest,
+};
+
+/// A client and the runtime its calls block on
+pub struct ElevenLabsTTVFfiClient {
+    client: ElevenLabsTTVClient,
+    runtime: tokio::runtime::Runtime,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(error: impl ToString) {
+    // Interior NULs would truncate the message in C anyway
+    let message = error.to_string().replace('\0', " ");
+    LAST_ERROR.with(|last| *last.borrow_mut() = CString::new(message).ok());
+}
+
+/// Run the body of an exported function, returning `on_panic` and recording the panic
+/// message as the last error if it panics
+fn catch_panic<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown panic");
+        set_last_error(format!("panic: {}", message));
+        on_panic
+    })
+}
+
+/// Borrow a C string argument, recording an error if it is NULL or not UTF-8
+///
+/// # Safety
+/// `string` must be NULL or a valid NUL-terminated string that outlives `'a`.
+unsafe fn str_arg<'a>(string: *const c_char, name: &str) -> Option<&'a str> {
+    if string.is_null() {
+        set_last_error(format!("{} is NULL", name));
+        return None;
+    }
+    match unsafe { CStr::from_ptr(string) }.to_str() {
+        Ok(string) => Some(string),
+        Err(_) => {
+            set_last_error(format!("{} is not valid UTF-8", name));
+            None
+        }
+    }
+}
+
+/// Parse a JSON request, run it and return the JSON response as a C string, or NULL
+///
+/// # Safety
+/// `client` must be NULL or from `elevenlabs_ttv_client_new`, `request_json` NULL or a valid
+/// NUL-terminated string.
+unsafe fn run_json<Req, Resp, F>(
+    client: *const ElevenLabsTTVFfiClient,
+    request_json: *const c_char,
+    run: F,
+) -> *mut c_char
+where
+    Req: DeserializeOwned,
+    Resp: Serialize,
+    F: AsyncFnOnce(&ElevenLabsTTVClient, Req) -> Result<Resp, ElevenLabsTTVError>,
+{
+    let Some(client) = (unsafe { client.as_ref() }) else {
+        set_last_error("client is NULL");
+        return ptr::null_mut();
+    };
+    let Some(request_json) = (unsafe { str_arg(request_json, "request_json") }) else {
+        return ptr::null_mut();
+    };
+
+    let result = client.runtime.block_on(async {
+        let request = serde_json::from_str::<Req>(request_json)?;
+        let response = run(&client.client, request).await?;
+        Ok::<_, ElevenLabsTTVError>(serde_json::to_string(&response)?)
+    });
+    match result {
+        // JSON escapes NUL characters, so the response never contains one
+        Ok(response) => CString::new(response).map_or(ptr::null_mut(), CString::into_raw),
+        Err(error) => {
+            set_last_error(error);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Create a client, or return NULL on failure. Free it with `elevenlabs_ttv_client_free`
+///
+/// # Safety
+/// `api_key` must be NULL or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_client_new(
+    api_key: *const c_char,
+) -> *mut ElevenLabsTTVFfiClient {
+    catch_panic(ptr::null_mut(), || {
+        let Some(api_key) = (unsafe { str_arg(api_key, "api_key") }) else {
+            return ptr::null_mut();
+        };
+        let runtime = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => runtime,
+            Err(error) => {
+                set_last_error(error);
+                return ptr::null_mut();
+            }
+        };
+
+        Box::into_raw(Box::new(ElevenLabsTTVFfiClient {
+            client: ElevenLabsTTVClient::new(api_key),
+            runtime,
+        }))
+    })
+}
+
+/// Free a client. NULL is ignored
+///
+/// # Safety
+/// `client` must be NULL or from `elevenlabs_ttv_client_new`, and not used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_client_free(client: *mut ElevenLabsTTVFfiClient) {
+    catch_panic((), || {
+        if !client.is_null() {
+            drop(unsafe { Box::from_raw(client) });
+        }
+    })
+}
+
+/// Validate and send a Design Voice request (JSON), returning the response JSON or NULL.
+/// Free the response with `elevenlabs_ttv_string_free`
+///
+/// # Safety
+/// `client` must be NULL or from `elevenlabs_ttv_client_new`, `request_json` NULL or a valid
+/// NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_design(
+    client: *const ElevenLabsTTVFfiClient,
+    request_json: *const c_char,
+) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || unsafe {
+        run_json(
+            client,
+            request_json,
+            async |client, request: TTVDesignVoiceRequest| {
+                request.validate()?;
+                client.execute_design_voice(request).await
+            },
+        )
+    })
+}
+
+/// Validate and send a Create Voice request (JSON), returning the response JSON or NULL.
+/// Free the response with `elevenlabs_ttv_string_free`
+///
+/// # Safety
+/// `client` must be NULL or from `elevenlabs_ttv_client_new`, `request_json` NULL or a valid
+/// NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_create(
+    client: *const ElevenLabsTTVFfiClient,
+    request_json: *const c_char,
+) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || unsafe {
+        run_json(
+            client,
+            request_json,
+            async |client, request: TTVCreateVoiceRequest| {
+                request.validate()?;
+                client.execute_create_voice(request).await
+            },
+        )
+    })
+}
+
+/// Download the audio of a preview generated with `stream_previews`, storing it in `*audio`
+/// and `*audio_len`. Returns 0, or -1 on failure. Free the audio with
+/// `elevenlabs_ttv_bytes_free`
+///
+/// # Safety
+/// `client` must be NULL or from `elevenlabs_ttv_client_new`, `generated_voice_id` NULL or a
+/// valid NUL-terminated string, and `audio` and `audio_len` NULL or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_fetch_preview(
+    client: *const ElevenLabsTTVFfiClient,
+    generated_voice_id: *const c_char,
+    audio: *mut *mut u8,
+    audio_len: *mut usize,
+) -> c_int {
+    catch_panic(-1, || {
+        let Some(client) = (unsafe { client.as_ref() }) else {
+            set_last_error("client is NULL");
+            return -1;
+        };
+        let Some(generated_voice_id) =
+            (unsafe { str_arg(generated_voice_id, "generated_voice_id") })
+        else {
+            return -1;
+        };
+        if audio.is_null() || audio_len.is_null() {
+            set_last_error("audio and audio_len must not be NULL");
+            return -1;
+        }
+
+        match client
+            .runtime
+            .block_on(client.client.stream_preview(generated_voice_id))
+        {
+            Ok(bytes) => {
+                let bytes: Box<[u8]> = bytes.to_vec().into_boxed_slice();
+                unsafe {
+                    *audio_len = bytes.len();
+                    *audio = Box::into_raw(bytes).cast();
+                }
+                0
+            }
+            Err(error) => {
+                set_last_error(error);
+                -1
+            }
+        }
+    })
+}
+
+/// Message of the last error on this thread, or NULL. Valid until the next failing call on
+/// the same thread; do not free it
+#[unsafe(no_mangle)]
+pub extern "C" fn elevenlabs_ttv_last_error() -> *const c_char {
+    catch_panic(ptr::null(), || {
+        LAST_ERROR.with(|last| {
+            last.borrow()
+                .as_ref()
+                .map_or(ptr::null(), |message| message.as_ptr())
+        })
+    })
+}
+
+/// Free a string returned by this library. NULL is ignored
+///
+/// # Safety
+/// `string` must be NULL or from `elevenlabs_ttv_design`/`elevenlabs_ttv_create`, and not
+/// used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_string_free(string: *mut c_char) {
+    catch_panic((), || {
+        if !string.is_null() {
+            drop(unsafe { CString::from_raw(string) });
+        }
+    })
+}
+
+/// Free audio returned by `elevenlabs_ttv_fetch_preview`. NULL is ignored
+///
+/// # Safety
+/// `audio` and `audio_len` must be NULL or exactly as returned by
+/// `elevenlabs_ttv_fetch_preview`, and the audio not used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn elevenlabs_ttv_bytes_free(audio: *mut u8, audio_len: usize) {
+    catch_panic((), || {
+        if !audio.is_null() {
+            drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(audio, audio_len)) });
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(elevenlabs_ttv_last_error()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_ffi_reports_panics() {
+        let result = catch_panic(ptr::null_mut::<c_char>(), || panic!("boom"));
+        assert!(result.is_null());
+        assert_eq!(last_error(), "panic: boom");
+
+        assert_eq!(catch_panic(-1, || panic!("{} failed", "fetch")), -1);
+        assert_eq!(last_error(), "panic: fetch failed");
+    }
+
+    #[test]
+    fn test_ffi_reports_errors() {
+        unsafe {
+            assert!(elevenlabs_ttv_client_new(ptr::null()).is_null());
+            assert_eq!(last_error(), "api_key is NULL");
+
+            let client = elevenlabs_ttv_client_new(c"test-key".as_ptr());
+            assert!(!client.is_null());
+
+            assert!(elevenlabs_ttv_design(client, c"not json".as_ptr()).is_null());
+            assert!(
+                last_error().starts_with("Serialization error"),
+                "{}",
+                last_error()
+            );
+
+            let request = cr#"{"voice_description": "Calm", "model_id": null}"#;
+            assert!(elevenlabs_ttv_design(client, request.as_ptr()).is_null());
+            assert!(
+                last_error().contains("voice_description"),
+                "{}",
+                last_error()
+            );
+
+            elevenlabs_ttv_client_free(client);
+        }
+    }
+}
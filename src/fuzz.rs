@@ -0,0 +1,89 @@
+//! `arbitrary::Arbitrary` support for the request and response types (`fuzz` feature)
+//!
+//! Request and response types derive `Arbitrary`, so property-based tests and fuzz targets
+//! can generate them from raw bytes:
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use elevenlabs_ttv::TTVDesignVoiceRequest;
+//!
+//! let mut bytes = Unstructured::new(&[7; 256]);
+//! let request = TTVDesignVoiceRequest::arbitrary(&mut bytes).unwrap();
+//! let _ = request.violations();
+//! ```
+//!
+//! Untyped JSON fields (`extra_body`, `settings`...) are filled with JSON scalars.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use serde_json::{Map, Number, Value};
+
+/// A JSON null, boolean, finite number or string
+pub(crate) fn arbitrary_json(u: &mut Unstructured<'_>) -> Result<Value> {
+    Ok(match u.int_in_range(0..=4)? {
+        0 => Value::Null,
+        1 => Value::Bool(bool::arbitrary(u)?),
+        2 => Value::Number(i64::arbitrary(u)?.into()),
+        3 => Number::from_f64(f64::arbitrary(u)?).map_or(Value::Null, Value::Number),
+        _ => Value::String(String::arbitrary(u)?),
+    })
+}
+
+pub(crate) fn arbitrary_optional_json(u: &mut Unstructured<'_>) -> Result<Option<Value>> {
+    if bool::arbitrary(u)? {
+        arbitrary_json(u).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+pub(crate) fn arbitrary_json_map(u: &mut Unstructured<'_>) -> Result<Map<String, Value>> {
+    let mut map = Map::new();
+    for _ in 0..u.arbitrary_len::<(String, u64)>()? {
+        map.insert(String::arbitrary(u)?, arbitrary_json(u)?);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TTVCreateVoiceRequest, TTVDesignVoiceRequest, TTVDesignVoiceResponse, Voice};
+
+    /// Pseudo-random inputs for `Unstructured`, deterministic so failures reproduce
+    fn inputs() -> impl Iterator<Item = Vec<u8>> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        (0..500).map(move |i| {
+            (0..64 + i % 512)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_arbitrary_requests_validate_and_serialize() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let design = TTVDesignVoiceRequest::arbitrary(&mut u).unwrap();
+            let _ = design.violations();
+            serde_json::to_string(&design).unwrap();
+
+            let create = TTVCreateVoiceRequest::arbitrary(&mut u).unwrap();
+            let _ = create.violations();
+            serde_json::to_string(&create).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_responses_serialize() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            serde_json::to_string(&TTVDesignVoiceResponse::arbitrary(&mut u).unwrap()).unwrap();
+            serde_json::to_string(&Voice::arbitrary(&mut u).unwrap()).unwrap();
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, HistoryItem, HistoryResponse};
+
+/// Builder for History requests (GET /v1/history), created with `ElevenLabsTTVClient::history()`
+pub struct HistoryBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
+    pub page_size: Option<u32>,
+    pub start_after_history_item_id: Option<String>,
+    pub voice_id: Option<String>,
+    pub search: Option<String>,
+    pub source: Option<String>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start building a History request, listing generated items (newest first)
+    pub fn history(&self) -> HistoryBuilder<'_> {
+        HistoryBuilder {
+            client: self,
+            page_size: None,
+            start_after_history_item_id: None,
+            voice_id: None,
+            search: None,
+            source: None,
+        }
+    }
+}
+
+impl<'a> HistoryBuilder<'a> {
+    /// How many history items to return per page, at most 1000. Defaults to 100.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Start listing after this history item (use `last_history_item_id` of the previous page)
+    pub fn start_after_history_item_id<S: Into<String>>(mut self, history_item_id: S) -> Self {
+        self.start_after_history_item_id = Some(history_item_id.into());
+        self
+    }
+
+    /// Only return items generated with this voice
+    pub fn voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Search term used for filtering
+    pub fn search<S: Into<String>>(mut self, search: S) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    /// Source of the generated items. Possible values: TTS, STS.
+    pub fn source<S: Into<String>>(mut self, source: S) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Fetch one page of history
+    pub async fn execute(self) -> Result<HistoryResponse, ElevenLabsTTVError> {
+        self.fetch_page().await
+    }
+
+    async fn fetch_page(&self) -> Result<HistoryResponse, ElevenLabsTTVError> {
+        let url = self.client.url(&["history"])?;
+
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        if let Some(start_after) = &self.start_after_history_item_id {
+            query.push(("start_after_history_item_id", start_after.clone()));
+        }
+        if let Some(voice_id) = &self.voice_id {
+            query.push(("voice_id", voice_id.clone()));
+        }
+        if let Some(search) = &self.search {
+            query.push(("search", search.clone()));
+        }
+        if let Some(source) = &self.source {
+            query.push(("source", source.clone()));
+        }
+
+        self.client
+            .send_json("history", self.client.inner.client.get(url).query(&query))
+            .await
+    }
+
+    /// Fetch every page of history, following `last_history_item_id` until `has_more` is false
+    ///
+    /// Fails with an `ApiError` if the server reports more pages without advancing the cursor.
+    pub async fn fetch_all(mut self) -> Result<Vec<HistoryItem>, ElevenLabsTTVError> {
+        let mut items = Vec::new();
+
+        loop {
+            let page = self.fetch_page().await?;
+            items.extend(page.history);
+
+            match page.last_history_item_id {
+                Some(last) if page.has_more => {
+                    if self.start_after_history_item_id.as_deref() == Some(last.as_str()) {
+                        return Err(ElevenLabsTTVError::ApiError {
+                            status: 200,
+                            message: format!("history pagination did not advance past {}", last),
+                            context: None,
+                        });
+                    }
+                    self.start_after_history_item_id = Some(last);
+                }
+                _ => return Ok(items),
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use reqwest::{Request, Response};
+
+/// Callbacks invoked around every HTTP request made by the client, e.g. for custom
+/// logging, header mutation or billing accounting
+///
+/// Register one with `ElevenLabsTTVClient::builder(api_key).hooks(hooks)`, or single
+/// closures with `.on_request(...)`, `.on_response(...)` and `.on_retry(...)`.
+/// All methods default to doing nothing.
+pub trait RequestHooks: Send + Sync {
+    /// Called right before a request is sent (including retries), after authentication
+    /// headers were added. The request can be modified, e.g. to add headers.
+    fn on_request(&self, endpoint: &'static str, request: &mut Request) {
+        let _ = (endpoint, request);
+    }
+
+    /// Called when response headers were received, for successful and error statuses
+    fn on_response(&self, endpoint: &'static str, response: &Response) {
+        let _ = (endpoint, response);
+    }
+
+    /// Called when a failed request is about to be retried
+    fn on_retry(&self, retry: &RetryInfo) {
+        let _ = retry;
+    }
+}
+
+/// Why and how a request is retried, see `RequestHooks::on_retry`
+#[derive(Debug, Clone)]
+pub struct RetryInfo {
+    /// Endpoint path template, e.g. "text-to-voice/design"
+    pub endpoint: &'static str,
+    /// Number of the upcoming attempt (2 for the first retry)
+    pub attempt: usize,
+    /// Status of the failed attempt, `None` if no response was received
+    pub status: Option<u16>,
+    /// Short description of the retry reason, e.g. "rotating API key"
+    pub reason: String,
+}
+
+/// `RequestHooks` running a closure on every request
+pub(crate) struct OnRequest<F>(pub(crate) F);
+
+impl<F> RequestHooks for OnRequest<F>
+where
+    F: Fn(&'static str, &mut Request) + Send + Sync,
+{
+    fn on_request(&self, endpoint: &'static str, request: &mut Request) {
+        (self.0)(endpoint, request)
+    }
+}
+
+/// `RequestHooks` running a closure on every response
+pub(crate) struct OnResponse<F>(pub(crate) F);
+
+impl<F> RequestHooks for OnResponse<F>
+where
+    F: Fn(&'static str, &Response) + Send + Sync,
+{
+    fn on_response(&self, endpoint: &'static str, response: &Response) {
+        (self.0)(endpoint, response)
+    }
+}
+
+/// `RequestHooks` running a closure on every retry
+pub(crate) struct OnRetry<F>(pub(crate) F);
+
+impl<F> RequestHooks for OnRetry<F>
+where
+    F: Fn(&RetryInfo) + Send + Sync,
+{
+    fn on_retry(&self, retry: &RetryInfo) {
+        (self.0)(retry)
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// API keys used by the client, rotated when one hits rate limits or runs out of quota
+///
+/// Shared by all clones of a client, so a rotation is seen by every task.
+#[derive(Debug)]
+pub(crate) struct KeyPool {
+    keys: Vec<String>,
+    current: AtomicUsize,
+}
+
+impl KeyPool {
+    pub(crate) fn new(keys: Vec<String>) -> Self {
+        Self {
+            keys,
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// The key currently in use, with its index
+    pub(crate) fn current(&self) -> (usize, &str) {
+        let index = self.current.load(Ordering::Relaxed) % self.keys.len();
+        (index, &self.keys[index])
+    }
+
+    /// Move to the key after `index`. Does nothing if another task already rotated away from it.
+    pub(crate) fn rotate_from(&self, index: usize) {
+        let next = (index + 1) % self.keys.len();
+        let _ = self
+            .current
+            .compare_exchange(index, next, Ordering::Relaxed, Ordering::Relaxed);
+    }
+}
+
+/// Whether an error response means the key is exhausted and the next one should be tried
+pub(crate) fn should_rotate(status: u16, message: &str) -> bool {
+    match status {
+        429 | 402 => true,
+        401 => message.contains("quota_exceeded"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        let pool = KeyPool::new(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(pool.current(), (0, "a"));
+
+        pool.rotate_from(0);
+        assert_eq!(pool.current(), (1, "b"));
+
+        // A stale rotation from another task is ignored
+        pool.rotate_from(0);
+        assert_eq!(pool.current(), (1, "b"));
+
+        pool.rotate_from(1);
+        assert_eq!(pool.current(), (0, "a"));
+    }
+
+    #[test]
+    fn test_should_rotate() {
+        assert!(should_rotate(429, "too_many_concurrent_requests"));
+        assert!(should_rotate(
+            401,
+            r#"{"detail":{"status":"quota_exceeded"}}"#
+        ));
+        assert!(!should_rotate(
+            401,
+            r#"{"detail":{"status":"invalid_api_key"}}"#
+        ));
+        assert!(!should_rotate(422, ""));
+    }
+}
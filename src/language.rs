@@ -0,0 +1,495 @@
+//! ISO 639-1 language codes, as reported for previews and verified voice languages
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{TTVCreateVoiceResponse, TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview};
+
+/// ISO 639-1 code of a language the API generates speech in, e.g. `LanguageCode::De` for "de"
+///
+/// Parsing is case-insensitive. Codes this crate does not know yet (including regional
+/// variants like "pt-BR") are kept as `Unknown`, and serialize back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum LanguageCode {
+    /// Afrikaans
+    Af,
+    /// Arabic
+    Ar,
+    /// Azerbaijani
+    Az,
+    /// Belarusian
+    Be,
+    /// Bulgarian
+    Bg,
+    /// Bengali
+    Bn,
+    /// Bosnian
+    Bs,
+    /// Catalan
+    Ca,
+    /// Czech
+    Cs,
+    /// Welsh
+    Cy,
+    /// Danish
+    Da,
+    /// German
+    De,
+    /// Greek
+    El,
+    /// English
+    En,
+    /// Spanish
+    Es,
+    /// Estonian
+    Et,
+    /// Persian
+    Fa,
+    /// Finnish
+    Fi,
+    /// French
+    Fr,
+    /// Irish
+    Ga,
+    /// Galician
+    Gl,
+    /// Gujarati
+    Gu,
+    /// Hebrew
+    He,
+    /// Hindi
+    Hi,
+    /// Croatian
+    Hr,
+    /// Hungarian
+    Hu,
+    /// Armenian
+    Hy,
+    /// Indonesian
+    Id,
+    /// Icelandic
+    Is,
+    /// Italian
+    It,
+    /// Japanese
+    Ja,
+    /// Georgian
+    Ka,
+    /// Kazakh
+    Kk,
+    /// Kannada
+    Kn,
+    /// Korean
+    Ko,
+    /// Lithuanian
+    Lt,
+    /// Latvian
+    Lv,
+    /// Macedonian
+    Mk,
+    /// Malayalam
+    Ml,
+    /// Marathi
+    Mr,
+    /// Malay
+    Ms,
+    /// Nepali
+    Ne,
+    /// Dutch
+    Nl,
+    /// Norwegian
+    No,
+    /// Punjabi
+    Pa,
+    /// Polish
+    Pl,
+    /// Portuguese
+    Pt,
+    /// Romanian
+    Ro,
+    /// Russian
+    Ru,
+    /// Slovak
+    Sk,
+    /// Slovenian
+    Sl,
+    /// Serbian
+    Sr,
+    /// Swedish
+    Sv,
+    /// Swahili
+    Sw,
+    /// Tamil
+    Ta,
+    /// Telugu
+    Te,
+    /// Thai
+    Th,
+    /// Tagalog
+    Tl,
+    /// Turkish
+    Tr,
+    /// Ukrainian
+    Uk,
+    /// Urdu
+    Ur,
+    /// Vietnamese
+    Vi,
+    /// Chinese
+    Zh,
+    /// A code not listed above, as sent by the API
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// Every known language, in code order
+    pub const ALL: &[LanguageCode] = &[
+        LanguageCode::Af,
+        LanguageCode::Ar,
+        LanguageCode::Az,
+        LanguageCode::Be,
+        LanguageCode::Bg,
+        LanguageCode::Bn,
+        LanguageCode::Bs,
+        LanguageCode::Ca,
+        LanguageCode::Cs,
+        LanguageCode::Cy,
+        LanguageCode::Da,
+        LanguageCode::De,
+        LanguageCode::El,
+        LanguageCode::En,
+        LanguageCode::Es,
+        LanguageCode::Et,
+        LanguageCode::Fa,
+        LanguageCode::Fi,
+        LanguageCode::Fr,
+        LanguageCode::Ga,
+        LanguageCode::Gl,
+        LanguageCode::Gu,
+        LanguageCode::He,
+        LanguageCode::Hi,
+        LanguageCode::Hr,
+        LanguageCode::Hu,
+        LanguageCode::Hy,
+        LanguageCode::Id,
+        LanguageCode::Is,
+        LanguageCode::It,
+        LanguageCode::Ja,
+        LanguageCode::Ka,
+        LanguageCode::Kk,
+        LanguageCode::Kn,
+        LanguageCode::Ko,
+        LanguageCode::Lt,
+        LanguageCode::Lv,
+        LanguageCode::Mk,
+        LanguageCode::Ml,
+        LanguageCode::Mr,
+        LanguageCode::Ms,
+        LanguageCode::Ne,
+        LanguageCode::Nl,
+        LanguageCode::No,
+        LanguageCode::Pa,
+        LanguageCode::Pl,
+        LanguageCode::Pt,
+        LanguageCode::Ro,
+        LanguageCode::Ru,
+        LanguageCode::Sk,
+        LanguageCode::Sl,
+        LanguageCode::Sr,
+        LanguageCode::Sv,
+        LanguageCode::Sw,
+        LanguageCode::Ta,
+        LanguageCode::Te,
+        LanguageCode::Th,
+        LanguageCode::Tl,
+        LanguageCode::Tr,
+        LanguageCode::Uk,
+        LanguageCode::Ur,
+        LanguageCode::Vi,
+        LanguageCode::Zh,
+    ];
+
+    /// Whether both are the same language, comparing codes case-insensitively so that
+    /// `Unknown("pt-BR")` matches "pt-br"
+    pub fn matches(&self, other: &LanguageCode) -> bool {
+        self.code().eq_ignore_ascii_case(other.code())
+    }
+
+    /// The code, e.g. "de"
+    pub fn code(&self) -> &str {
+        match self {
+            LanguageCode::Af => "af",
+            LanguageCode::Ar => "ar",
+            LanguageCode::Az => "az",
+            LanguageCode::Be => "be",
+            LanguageCode::Bg => "bg",
+            LanguageCode::Bn => "bn",
+            LanguageCode::Bs => "bs",
+            LanguageCode::Ca => "ca",
+            LanguageCode::Cs => "cs",
+            LanguageCode::Cy => "cy",
+            LanguageCode::Da => "da",
+            LanguageCode::De => "de",
+            LanguageCode::El => "el",
+            LanguageCode::En => "en",
+            LanguageCode::Es => "es",
+            LanguageCode::Et => "et",
+            LanguageCode::Fa => "fa",
+            LanguageCode::Fi => "fi",
+            LanguageCode::Fr => "fr",
+            LanguageCode::Ga => "ga",
+            LanguageCode::Gl => "gl",
+            LanguageCode::Gu => "gu",
+            LanguageCode::He => "he",
+            LanguageCode::Hi => "hi",
+            LanguageCode::Hr => "hr",
+            LanguageCode::Hu => "hu",
+            LanguageCode::Hy => "hy",
+            LanguageCode::Id => "id",
+            LanguageCode::Is => "is",
+            LanguageCode::It => "it",
+            LanguageCode::Ja => "ja",
+            LanguageCode::Ka => "ka",
+            LanguageCode::Kk => "kk",
+            LanguageCode::Kn => "kn",
+            LanguageCode::Ko => "ko",
+            LanguageCode::Lt => "lt",
+            LanguageCode::Lv => "lv",
+            LanguageCode::Mk => "mk",
+            LanguageCode::Ml => "ml",
+            LanguageCode::Mr => "mr",
+            LanguageCode::Ms => "ms",
+            LanguageCode::Ne => "ne",
+            LanguageCode::Nl => "nl",
+            LanguageCode::No => "no",
+            LanguageCode::Pa => "pa",
+            LanguageCode::Pl => "pl",
+            LanguageCode::Pt => "pt",
+            LanguageCode::Ro => "ro",
+            LanguageCode::Ru => "ru",
+            LanguageCode::Sk => "sk",
+            LanguageCode::Sl => "sl",
+            LanguageCode::Sr => "sr",
+            LanguageCode::Sv => "sv",
+            LanguageCode::Sw => "sw",
+            LanguageCode::Ta => "ta",
+            LanguageCode::Te => "te",
+            LanguageCode::Th => "th",
+            LanguageCode::Tl => "tl",
+            LanguageCode::Tr => "tr",
+            LanguageCode::Uk => "uk",
+            LanguageCode::Ur => "ur",
+            LanguageCode::Vi => "vi",
+            LanguageCode::Zh => "zh",
+            LanguageCode::Unknown(code) => code,
+        }
+    }
+
+    /// English name of the language, e.g. "German", or `None` for `Unknown` codes
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            LanguageCode::Af => "Afrikaans",
+            LanguageCode::Ar => "Arabic",
+            LanguageCode::Az => "Azerbaijani",
+            LanguageCode::Be => "Belarusian",
+            LanguageCode::Bg => "Bulgarian",
+            LanguageCode::Bn => "Bengali",
+            LanguageCode::Bs => "Bosnian",
+            LanguageCode::Ca => "Catalan",
+            LanguageCode::Cs => "Czech",
+            LanguageCode::Cy => "Welsh",
+            LanguageCode::Da => "Danish",
+            LanguageCode::De => "German",
+            LanguageCode::El => "Greek",
+            LanguageCode::En => "English",
+            LanguageCode::Es => "Spanish",
+            LanguageCode::Et => "Estonian",
+            LanguageCode::Fa => "Persian",
+            LanguageCode::Fi => "Finnish",
+            LanguageCode::Fr => "French",
+            LanguageCode::Ga => "Irish",
+            LanguageCode::Gl => "Galician",
+            LanguageCode::Gu => "Gujarati",
+            LanguageCode::He => "Hebrew",
+            LanguageCode::Hi => "Hindi",
+            LanguageCode::Hr => "Croatian",
+            LanguageCode::Hu => "Hungarian",
+            LanguageCode::Hy => "Armenian",
+            LanguageCode::Id => "Indonesian",
+            LanguageCode::Is => "Icelandic",
+            LanguageCode::It => "Italian",
+            LanguageCode::Ja => "Japanese",
+            LanguageCode::Ka => "Georgian",
+            LanguageCode::Kk => "Kazakh",
+            LanguageCode::Kn => "Kannada",
+            LanguageCode::Ko => "Korean",
+            LanguageCode::Lt => "Lithuanian",
+            LanguageCode::Lv => "Latvian",
+            LanguageCode::Mk => "Macedonian",
+            LanguageCode::Ml => "Malayalam",
+            LanguageCode::Mr => "Marathi",
+            LanguageCode::Ms => "Malay",
+            LanguageCode::Ne => "Nepali",
+            LanguageCode::Nl => "Dutch",
+            LanguageCode::No => "Norwegian",
+            LanguageCode::Pa => "Punjabi",
+            LanguageCode::Pl => "Polish",
+            LanguageCode::Pt => "Portuguese",
+            LanguageCode::Ro => "Romanian",
+            LanguageCode::Ru => "Russian",
+            LanguageCode::Sk => "Slovak",
+            LanguageCode::Sl => "Slovenian",
+            LanguageCode::Sr => "Serbian",
+            LanguageCode::Sv => "Swedish",
+            LanguageCode::Sw => "Swahili",
+            LanguageCode::Ta => "Tamil",
+            LanguageCode::Te => "Telugu",
+            LanguageCode::Th => "Thai",
+            LanguageCode::Tl => "Tagalog",
+            LanguageCode::Tr => "Turkish",
+            LanguageCode::Uk => "Ukrainian",
+            LanguageCode::Ur => "Urdu",
+            LanguageCode::Vi => "Vietnamese",
+            LanguageCode::Zh => "Chinese",
+            LanguageCode::Unknown(_) => return None,
+        })
+    }
+}
+
+impl FromStr for LanguageCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(LanguageCode::ALL
+            .iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+            .cloned()
+            .unwrap_or_else(|| LanguageCode::Unknown(code.to_string())))
+    }
+}
+
+impl From<&str> for LanguageCode {
+    fn from(code: &str) -> Self {
+        let Ok(language) = code.parse();
+        language
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(code: String) -> Self {
+        code.as_str().into()
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for LanguageCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+impl TTVDesignVoiceResponse {
+    /// The previews in `language`, e.g. `response.previews_for_language(LanguageCode::De)`
+    /// or `response.previews_for_language("de")`
+    pub fn previews_for_language(
+        &self,
+        language: impl Into<LanguageCode>,
+    ) -> impl Iterator<Item = &TTVDesignVoiceResponseVoicePreview> {
+        let language = language.into();
+        self.previews.iter().filter(move |preview| {
+            preview
+                .language
+                .as_ref()
+                .is_some_and(|code| code.matches(&language))
+        })
+    }
+
+    /// The previews in `language`, same as `previews_for_language`
+    pub fn previews_in(
+        &self,
+        language: impl Into<LanguageCode>,
+    ) -> impl Iterator<Item = &TTVDesignVoiceResponseVoicePreview> {
+        self.previews_for_language(language)
+    }
+
+    /// The distinct languages of the previews, in order of first appearance. Previews
+    /// without a language are left out.
+    pub fn languages(&self) -> Vec<&LanguageCode> {
+        let mut languages = Vec::new();
+        for language in self.previews.iter().filter_map(|p| p.language.as_ref()) {
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+        languages
+    }
+}
+
+impl TTVCreateVoiceResponse {
+    /// Whether the voice is verified for `language`
+    pub fn is_verified_in(&self, language: &LanguageCode) -> bool {
+        self.verified_languages
+            .iter()
+            .flatten()
+            .any(|verified| verified.language.matches(language))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_code_round_trip() {
+        assert_eq!(LanguageCode::from("DE"), LanguageCode::De);
+        assert_eq!(LanguageCode::De.name(), Some("German"));
+        assert_eq!(serde_json::to_string(&LanguageCode::De).unwrap(), "\"de\"");
+
+        let regional: LanguageCode = serde_json::from_str("\"pt-BR\"").unwrap();
+        assert_eq!(regional, LanguageCode::Unknown("pt-BR".to_string()));
+        assert_eq!(regional.to_string(), "pt-BR");
+        assert!(regional.matches(&LanguageCode::from("pt-br")));
+        assert!(!regional.matches(&LanguageCode::from("pt")));
+    }
+
+    #[test]
+    fn test_previews_for_language() {
+        let response: TTVDesignVoiceResponse = serde_json::from_value(serde_json::json!({
+            "previews": [
+                { "audio_base_64": "", "generated_voice_id": "gen-1", "media_type": "audio/mpeg",
+                  "duration_secs": 1.0, "language": "en" },
+                { "audio_base_64": "", "generated_voice_id": "gen-2", "media_type": "audio/mpeg",
+                  "duration_secs": 1.0, "language": "de" },
+                { "audio_base_64": "", "generated_voice_id": "gen-3", "media_type": "audio/mpeg",
+                  "duration_secs": 1.0, "language": null },
+                { "audio_base_64": "", "generated_voice_id": "gen-4", "media_type": "audio/mpeg",
+                  "duration_secs": 1.0, "language": "en" }
+            ],
+            "text": "Hallo"
+        }))
+        .unwrap();
+
+        let german: Vec<_> = response.previews_for_language(LanguageCode::De).collect();
+        assert_eq!(german.len(), 1);
+        assert_eq!(german[0].generated_voice_id, "gen-2");
+        assert_eq!(response.previews_for_language("EN").count(), 2);
+        assert_eq!(response.previews_in(LanguageCode::De).count(), german.len());
+
+        assert_eq!(response.languages(), [&LanguageCode::En, &LanguageCode::De]);
+    }
+}
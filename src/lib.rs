@@ -36,51 +36,298 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # Async runtimes
+//!
+//! The request path does not depend on a specific executor. The built-in reqwest transport
+//! needs a tokio reactor though; under async-std or smol, plug a runtime-agnostic transport
+//! in with `ElevenLabsTTVClientBuilder::http_backend` and disable the default `tokio`
+//! feature, which gates the helpers built on tokio (streamed file uploads, `AsyncWrite`
+//! sinks, polling, `AdaptiveScheduler` and `batch`).
+//!
+//! # TLS
+//!
+//! The built-in transport uses rustls (`rustls-tls`, default) or the platform's TLS library
+//! (`native-tls`). One of them must be enabled, unless `custom-backend` is: then the crate
+//! builds without TLS and every request must go through `http_backend`.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 
-use reqwest::Client;
+use reqwest::header::{AUTHORIZATION, HeaderName, HeaderValue};
+use reqwest::{Client, Request, RequestBuilder, Response, StatusCode, Url};
+use serde::de::DeserializeOwned;
 
+use circuit_breaker::CircuitBreaker;
+use etag::EtagCache;
+use key_pool::KeyPool;
+use otel::ApiSpan;
+use retry_budget::RetryBudgetTracker;
+use scheduler::RateLimitTracker;
+use usage::UsageTracker;
+
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod audition;
+pub mod backend;
+#[cfg(feature = "tokio")]
+pub mod batch;
+#[cfg(feature = "bevy")]
+pub mod bevy_assets;
+pub mod circuit_breaker;
+pub mod client_builder;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod cost;
+#[cfg(feature = "decode")]
+mod decode;
+pub mod decoded;
+pub mod deprecation;
+#[cfg(feature = "encode")]
+mod encode;
 pub mod error;
+mod etag;
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fuzz")]
+mod fuzz;
+pub mod history;
+pub mod hooks;
+mod key_pool;
+pub mod language;
+#[cfg(feature = "tokio")]
+mod limiter;
+mod logging;
+pub mod media_type;
+pub mod metrics;
 pub mod models;
+pub mod multilingual;
+mod otel;
+pub mod pcm;
+pub mod presets;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub mod preview_response;
+pub mod previews;
+pub mod progress;
+pub mod prompts;
+pub mod pvc;
+pub mod redact;
+pub mod reference_audio;
+pub mod remix;
+pub mod retry_budget;
+mod samples;
+pub mod scheduler;
+pub mod selector;
+#[cfg(feature = "tower")]
+mod service;
+pub mod session;
+pub mod similar_voices;
+#[cfg(feature = "tokio")]
+pub mod sink;
+mod speech;
+mod subscription;
+pub mod sweep;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+#[cfg(feature = "chrono")]
+mod timestamps;
 pub mod types;
+#[cfg(feature = "uniffi")]
+pub mod uniffi_api;
+pub mod usage;
+mod validation;
+pub mod vcr;
+pub mod verification;
+pub mod voice_clone;
+pub mod voice_description;
+mod voices;
 
-pub use error::ElevenLabsTTVError;
+pub use audition::AuditionTracker;
+pub use backend::{HttpBackend, ReqwestBackend};
+pub use circuit_breaker::CircuitState;
+pub use client_builder::{AuthScheme, ElevenLabsTTVClientBuilder};
+pub use decoded::{DecodedVoicePreview, TTVDesignVoiceDecodedResponse};
+pub use deprecation::ModelStatus;
+pub use error::{ElevenLabsTTVError, ErrorReport, RequestContext};
+pub use export::{PreviewManifest, PreviewManifestEntry};
+pub use hooks::{RequestHooks, RetryInfo};
+pub use language::LanguageCode;
+pub use media_type::MediaType;
+pub use metrics::{MetricsSink, RequestMetrics};
+pub use pcm::PcmAudio;
+pub use presets::DesignPreset;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use preview_response::PreviewResponse;
+pub use progress::ProgressReporter;
+pub use retry_budget::RetryBudget;
+#[cfg(feature = "tokio")]
+pub use scheduler::AdaptiveScheduler;
+pub use scheduler::{DEFAULT_RATE_LIMIT_COOLDOWN, MAX_RATE_LIMIT_COOLDOWN, RateLimitInfo};
+pub use selector::PreviewSelector;
+pub use session::DesignSession;
 pub use types::*;
+pub use usage::{UsageInfo, UsageTotals};
+pub use validation::{MAX_DESCRIPTION_LENGTH, MIN_DESCRIPTION_LENGTH, ParameterViolation};
+pub use voice_description::{DescriptionLint, Gender, Pace, VoiceDescription, lint_description};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
 /// Main client for interacting with ElevenLabs API
+///
+/// Cloning is cheap: all clones share the same HTTP connection pool and configuration.
 #[derive(Clone)]
 pub struct ElevenLabsTTVClient {
+    inner: Arc<ClientInner>,
+}
+
+/// Default `User-Agent` header, e.g. "elevenlabs_ttv/0.1.0 (rust)"
+pub const USER_AGENT: &str = concat!("elevenlabs_ttv/", env!("CARGO_PKG_VERSION"), " (rust)");
+
+/// HTTP client used by `new` and `with_base_url`
+fn default_http_client() -> Client {
+    http_client_builder()
+        .user_agent(USER_AGENT)
+        .build()
+        .expect("failed to initialize HTTP client")
+}
+
+// Without TLS the built-in client cannot reach the API, so that is only allowed when requests
+// go through a custom `HttpBackend`
+#[cfg(not(any(
+    feature = "rustls-tls",
+    feature = "native-tls",
+    feature = "custom-backend"
+)))]
+compile_error!(
+    "enable the `rustls-tls` (default) or `native-tls` feature, or `custom-backend` to send \
+     requests with `ElevenLabsTTVClientBuilder::http_backend` only"
+);
+
+/// reqwest client builder using the TLS backend selected by the crate features:
+/// `native-tls` when enabled, otherwise `rustls-tls` (default)
+fn http_client_builder() -> reqwest::ClientBuilder {
+    let builder = Client::builder();
+    #[cfg(feature = "native-tls")]
+    let builder = builder.use_native_tls();
+    #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+    let builder = builder.use_rustls_tls();
+    builder
+}
+
+/// Shared state behind every clone of a client
+struct ClientInner {
     client: Client,
-    api_key: String,
+    api_keys: KeyPool,
+    auth_scheme: AuthScheme,
     base_url: String,
+    metrics: Option<Arc<dyn MetricsSink>>,
+    hooks: Vec<Arc<dyn RequestHooks>>,
+    rate_limits: RateLimitTracker,
+    etag_cache: Option<EtagCache>,
+    usage: Option<UsageTracker>,
+    /// Retry design requests rejected by the v3 model with v2, see
+    /// `ElevenLabsTTVClientBuilder::model_fallback`
+    model_fallback: bool,
+    circuit_breaker: Option<CircuitBreaker>,
+    retry_budget: Option<RetryBudgetTracker>,
+    /// Permits for requests in flight, see `ElevenLabsTTVClientBuilder::max_concurrent_requests`
+    #[cfg(feature = "tokio")]
+    request_limiter: Option<Arc<tokio::sync::Semaphore>>,
+    /// Executes requests instead of `client` when set, see `ElevenLabsTTVClientBuilder::middleware`
+    #[cfg(feature = "middleware")]
+    middleware: Option<reqwest_middleware::ClientWithMiddleware>,
+    /// Executes requests instead of `client` when set, see `ElevenLabsTTVClientBuilder::http_backend`
+    backend: Option<Arc<dyn HttpBackend>>,
+}
+
+impl fmt::Debug for ElevenLabsTTVClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElevenLabsTTVClient")
+            .field(
+                "api_key",
+                &redact::redact_secret(self.inner.api_keys.current().1),
+            )
+            .field("api_key_count", &self.inner.api_keys.len())
+            .field("auth_scheme", &self.inner.auth_scheme)
+            .field("base_url", &self.inner.base_url)
+            .field("metrics", &self.inner.metrics.is_some())
+            .field("hooks", &self.inner.hooks.len())
+            .finish()
+    }
 }
 
 impl ElevenLabsTTVClient {
     /// Create a new ElevenLabs client with API key
     pub fn new<S: Into<String>>(api_key: S) -> Self {
-        Self {
-            client: Client::new(),
-            api_key: api_key.into(),
+        Self::from_inner(ClientInner {
+            client: default_http_client(),
+            api_keys: KeyPool::new(vec![api_key.into()]),
+            auth_scheme: AuthScheme::ApiKey,
             base_url: "https://api.elevenlabs.io/v1".to_string(),
-        }
+            metrics: None,
+            hooks: Vec::new(),
+            rate_limits: RateLimitTracker::default(),
+            etag_cache: Some(EtagCache::default()),
+            usage: None,
+            model_fallback: false,
+            circuit_breaker: None,
+            retry_budget: None,
+            #[cfg(feature = "tokio")]
+            request_limiter: None,
+            #[cfg(feature = "middleware")]
+            middleware: None,
+            backend: None,
+        })
     }
 
     /// Create a new client with custom base URL (for testing/enterprise)
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Self {
-        Self {
-            client: Client::new(),
-            api_key: api_key.into(),
+        Self::from_inner(ClientInner {
+            client: default_http_client(),
+            api_keys: KeyPool::new(vec![api_key.into()]),
+            auth_scheme: AuthScheme::ApiKey,
             base_url: base_url.into(),
+            metrics: None,
+            hooks: Vec::new(),
+            rate_limits: RateLimitTracker::default(),
+            etag_cache: Some(EtagCache::default()),
+            usage: None,
+            model_fallback: false,
+            circuit_breaker: None,
+            retry_budget: None,
+            #[cfg(feature = "tokio")]
+            request_limiter: None,
+            #[cfg(feature = "middleware")]
+            middleware: None,
+            backend: None,
+        })
+    }
+
+    fn from_inner(inner: ClientInner) -> Self {
+        Self {
+            inner: Arc::new(inner),
         }
     }
 
+    /// Start configuring a client with additional options (metrics, base URL, ...)
+    pub fn builder<S: Into<String>>(api_key: S) -> ElevenLabsTTVClientBuilder {
+        ElevenLabsTTVClientBuilder::new(api_key.into())
+    }
+
     /// Start building a Text-to-Voice: Design Voice request
     ///
-    /// Requires the description to use for the created voice.
+    /// Requires the description to use for the created voice, except with the eleven_ttv_v3
+    /// model and reference audio: the description can then be minimal or empty, letting the
+    /// reference audio drive the design (best with a `prompt_strength` close to 0).
     pub fn design_voice<S: Into<String>>(
         &self,
         voice_description: S,
-    ) -> TextToVoiceDesignVoiceBuilder {
-        TextToVoiceDesignVoiceBuilder::new(self.clone(), voice_description.into())
+    ) -> TextToVoiceDesignVoiceBuilder<'_> {
+        TextToVoiceDesignVoiceBuilder::new(self, voice_description.into())
     }
 
     /// Start building a Text-to-Voice: Create Voice request
@@ -97,89 +344,495 @@ impl ElevenLabsTTVClient {
         voice_name: S,
         voice_description: S,
         generated_voice_id: S,
-    ) -> TextToVoiceCreateVoiceBuilder {
+    ) -> TextToVoiceCreateVoiceBuilder<'_> {
         TextToVoiceCreateVoiceBuilder::new(
-            self.clone(),
+            self,
             voice_name.into(),
             voice_description.into(),
             generated_voice_id.into(),
         )
     }
 
-    /// Internal method to execute TTV: Design Voice request
-    pub(crate) async fn execute_design_voice(
+    /// Execute a prebuilt Text-to-Voice: Design Voice request, e.g. one loaded from a job queue
+    ///
+    /// The request is sent as-is: builder defaults (model, loudness, guidance scale, ...) are not applied.
+    pub async fn execute_design_voice(
+        &self,
+        request: TTVDesignVoiceRequest,
+    ) -> Result<TTVDesignVoiceResponse, ElevenLabsTTVError> {
+        let result = self
+            .send_design_voice(self.design_voice_request(&request)?)
+            .await;
+        self.with_model_fallback(result, request).await
+    }
+
+    /// Retry a design rejected by `eleven_ttv_v3` with `eleven_multilingual_ttv_v2`, when
+    /// `model_fallback` is enabled and the request is valid for v2
+    async fn with_model_fallback(
         &self,
+        result: Result<TTVDesignVoiceResponse, ElevenLabsTTVError>,
         request: TTVDesignVoiceRequest,
     ) -> Result<TTVDesignVoiceResponse, ElevenLabsTTVError> {
-        let mut url = format!("{}/text-to-voice/design", self.base_url);
+        let fallback = if self.inner.model_fallback
+            && request.model_id.as_deref() == Some(models::elevanlabs_models::ELEVEN_TTV_V3)
+        {
+            v2_fallback(request)
+        } else {
+            None
+        };
+
+        match (result, fallback) {
+            (
+                Err(ElevenLabsTTVError::ApiError {
+                    status: status @ (400 | 422),
+                    message,
+                    ..
+                }),
+                Some(fallback),
+            ) if self.retry_allowed("text-to-voice/design") => {
+                let fallback_model = models::elevanlabs_models::ELEVEN_MULTILINGUAL_TTV_V2;
+                let retry = RetryInfo {
+                    endpoint: "text-to-voice/design",
+                    attempt: 2,
+                    status: Some(status),
+                    reason: format!(
+                        "{} rejected the request ({}), falling back to {}",
+                        models::elevanlabs_models::ELEVEN_TTV_V3,
+                        message,
+                        fallback_model
+                    ),
+                };
+                logging::request_retried(&retry);
+                for hooks in &self.inner.hooks {
+                    hooks.on_retry(&retry);
+                }
+
+                self.send_design_voice(self.design_voice_request(&fallback)?)
+                    .await
+            }
+            (result, _) => result,
+        }
+    }
+
+    /// Send a Design Voice HTTP request and record the size of the returned previews
+    async fn send_design_voice(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<TTVDesignVoiceResponse, ElevenLabsTTVError> {
+        let endpoint = "text-to-voice/design";
+        let response: TTVDesignVoiceResponse = self.send_json(endpoint, request).await?;
+
+        let audio_bytes = response
+            .previews
+            .iter()
+            .map(|preview| metrics::decoded_base64_len(&preview.audio_base_64))
+            .sum();
+        self.record_audio_bytes(endpoint, audio_bytes);
+
+        Ok(response)
+    }
+
+    /// Internal method to build the HTTP request for TTV: Design Voice
+    fn design_voice_request(
+        &self,
+        request: &TTVDesignVoiceRequest,
+    ) -> Result<RequestBuilder, ElevenLabsTTVError> {
+        let url = self.url(&["text-to-voice", "design"])?;
 
         let output_format = request
             .output_format
             .clone()
             .unwrap_or_else(|| "mp3_44100_128".to_string()); // Default to: mp3_44100_128
 
-        url = format!("{}?output_format={}", url, output_format);
+        // output_format goes in the query string, not in the body
+        let body = json_body(request, &["output_format", "extra_query"])?;
+        let mut query = vec![("output_format".to_string(), output_format)];
+        query.extend(request.extra_query.iter().cloned());
+
+        Ok(self
+            .inner
+            .client
+            .post(url)
+            .query(&query)
+            .header("Content-Type", "application/json")
+            .json(&body))
+    }
+
+    /// Execute a prebuilt Text-to-Voice: Create Voice request, e.g. one loaded from a job queue
+    pub async fn execute_create_voice(
+        &self,
+        request: TTVCreateVoiceRequest,
+    ) -> Result<TTVCreateVoiceResponse, ElevenLabsTTVError> {
+        self.send_json("text-to-voice", self.create_voice_request(&request)?)
+            .await
+    }
 
-        let response = self
+    /// Internal method to build the HTTP request for TTV: Create Voice
+    fn create_voice_request(
+        &self,
+        request: &TTVCreateVoiceRequest,
+    ) -> Result<RequestBuilder, ElevenLabsTTVError> {
+        let url = self.url(&["text-to-voice"])?;
+        let body = json_body(request, &["extra_query"])?;
+
+        Ok(self
+            .inner
             .client
-            .post(&url)
-            .header("xi-api-key", &self.api_key)
+            .post(url)
+            .query(&request.extra_query)
             .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await?;
+            .json(&body))
+    }
+
+    /// Internal method to build an endpoint URL from the base URL and path segments
+    ///
+    /// Segments are percent-encoded, and a base URL with a path or trailing slash is handled.
+    pub(crate) fn url(&self, segments: &[&str]) -> Result<Url, ElevenLabsTTVError> {
+        let mut url = Url::parse(&self.inner.base_url).map_err(|e| {
+            ElevenLabsTTVError::ValidationError(format!(
+                "Invalid base URL '{}': {}",
+                self.inner.base_url, e
+            ))
+        })?;
+
+        url.path_segments_mut()
+            .map_err(|_| {
+                ElevenLabsTTVError::ValidationError(format!(
+                    "Invalid base URL '{}': cannot have a path",
+                    self.inner.base_url
+                ))
+            })?
+            .pop_if_empty()
+            .extend(segments);
+
+        Ok(url)
+    }
+
+    /// Internal method to authenticate and send a request, turning error statuses into `ApiError`
+    ///
+    /// When the client has several API keys, a rate-limit or quota error rotates to the next key
+    /// and retries, until every key has been tried once. A 429 that is not retried starts a
+    /// cooldown shared by all clones of the client: until it ends, every attempt waits for it
+    /// (or fails with `RateLimitError` without the `tokio` feature) instead of piling on.
+    ///
+    /// `endpoint` is the path template used to label metrics (e.g. "text-to-voice/design").
+    pub(crate) async fn send(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<Response, ElevenLabsTTVError> {
+        let mut request = request.build()?;
+        let mut attempt = 1;
+        if let Some(budget) = &self.inner.retry_budget {
+            budget.record_request();
+        }
+        // Moved into the returned response's body, so the slot is freed once it has been read
+        #[cfg(feature = "tokio")]
+        let mut permit = match &self.inner.request_limiter {
+            Some(limiter) => Some(
+                limiter
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("the request limiter is never closed"),
+            ),
+            None => None,
+        };
+
+        loop {
+            if let Some(remaining) = self.inner.rate_limits.cooldown_remaining() {
+                #[cfg(feature = "tokio")]
+                tokio::time::sleep(remaining).await;
+                #[cfg(not(feature = "tokio"))]
+                return Err(ElevenLabsTTVError::RateLimitError {
+                    retry_after: Some(remaining.as_secs_f64().ceil() as u64),
+                    message: "Waiting for the cooldown after a 429 response".to_string(),
+                });
+            }
+
+            let (key_index, api_key) = self.inner.api_keys.current();
+            let retry_request = if attempt < self.inner.api_keys.len() {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let (header_name, header_value) = match self.inner.auth_scheme {
+                AuthScheme::ApiKey => (HeaderName::from_static("xi-api-key"), api_key.to_string()),
+                AuthScheme::Bearer => (AUTHORIZATION, format!("Bearer {}", api_key)),
+            };
+            let mut header_value = HeaderValue::from_str(&header_value).map_err(|_| {
+                ElevenLabsTTVError::AuthenticationError(
+                    "API key contains invalid characters".to_string(),
+                )
+            })?;
+            header_value.set_sensitive(true);
+            request.headers_mut().insert(header_name, header_value);
+            for hooks in &self.inner.hooks {
+                hooks.on_request(endpoint, &mut request);
+            }
+            let method = request.method().clone();
+            let context = RequestContext::new(endpoint, &method, request.url());
+
+            if let Some(breaker) = &self.inner.circuit_breaker {
+                breaker.acquire()?;
+            }
+
+            let mut span = ApiSpan::start(endpoint, &request, attempt);
+            logging::request_started(endpoint, &method, attempt);
+            logging::request_body(
+                endpoint,
+                &method,
+                request.body().and_then(reqwest::Body::as_bytes),
+            );
+            let started = Instant::now();
+            let result = self
+                .execute(request)
+                .await
+                .map_err(|error| error.with_context(&context));
+            match &result {
+                Ok(response) => {
+                    span.record_status(response.status().as_u16());
+                    logging::request_completed(
+                        endpoint,
+                        &method,
+                        response.status().as_u16(),
+                        started.elapsed(),
+                    );
+                }
+                Err(error) => {
+                    span.record_error(error);
+                    logging::request_failed(endpoint, &method, error);
+                }
+            }
+            span.end();
+
+            if let Some(metrics) = &self.inner.metrics {
+                metrics.record_request(&RequestMetrics {
+                    endpoint,
+                    method,
+                    status: result
+                        .as_ref()
+                        .ok()
+                        .map(|response| response.status().as_u16()),
+                    latency: started.elapsed(),
+                });
+            }
+
+            if let Some(breaker) = &self.inner.circuit_breaker {
+                match &result {
+                    Ok(response) if !response.status().is_server_error() => {
+                        breaker.record_success()
+                    }
+                    _ => breaker.record_failure(),
+                }
+            }
 
-        if !response.status().is_success() {
-            // println!("Response: {:?}", response);
+            let mut response = result?;
+            response.extensions_mut().insert(context.clone());
+            self.inner.rate_limits.update(response.headers());
+            if let Some(usage) = &self.inner.usage {
+                usage.record(response.headers());
+            }
+            for hooks in &self.inner.hooks {
+                hooks.on_response(endpoint, &response);
+            }
+
+            // 304 only answers conditional requests, see `send_cached_json`
+            if response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED {
+                #[cfg(feature = "tokio")]
+                if let Some(permit) = permit.take() {
+                    response = limiter::hold_permit(response, permit);
+                }
+                return Ok(response);
+            }
+
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = response.text().await.unwrap_or_default();
+
+            if let Some(retry_request) = retry_request
+                && key_pool::should_rotate(status, &message)
+                && self.retry_allowed(endpoint)
+            {
+                self.inner.api_keys.rotate_from(key_index);
+                request = retry_request;
+                attempt += 1;
+                let retry = RetryInfo {
+                    endpoint,
+                    attempt,
+                    status: Some(status),
+                    reason: "rotating API key".to_string(),
+                };
+                logging::request_retried(&retry);
+                for hooks in &self.inner.hooks {
+                    hooks.on_retry(&retry);
+                }
+                continue;
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS.as_u16() {
+                self.inner.rate_limits.rate_limited(&headers);
+            }
             return Err(ElevenLabsTTVError::ApiError {
-                status: response.status().as_u16(),
-                message: response.text().await.unwrap_or_default(),
+                status,
+                message,
+                context: Some(context),
             });
         }
+    }
+
+    /// Whether the retry budget (if any) has room for one more retry, counting it if so
+    fn retry_allowed(&self, endpoint: &'static str) -> bool {
+        let allowed = self
+            .inner
+            .retry_budget
+            .as_ref()
+            .is_none_or(RetryBudgetTracker::try_retry);
+        if !allowed {
+            logging::retry_budget_exhausted(endpoint);
+        }
+        allowed
+    }
 
-        let parse_response = response.json::<TTVDesignVoiceResponse>().await;
+    /// Execute a single HTTP request, through the middleware stack if one is configured
+    async fn execute(&self, request: Request) -> Result<Response, ElevenLabsTTVError> {
+        #[cfg(feature = "middleware")]
+        if let Some(middleware) = &self.inner.middleware {
+            return middleware.execute(request).await.map_err(|e| match e {
+                reqwest_middleware::Error::Reqwest(e) => e.into(),
+                reqwest_middleware::Error::Middleware(e) => {
+                    ElevenLabsTTVError::MiddlewareError(format!("{:#}", e))
+                }
+            });
+        }
 
-        match parse_response {
-            Ok(ttv_response) => return Ok(ttv_response),
-            Err(e) => return Err(ElevenLabsTTVError::ParseError(e)),
+        if let Some(backend) = &self.inner.backend {
+            return backend::execute(backend.as_ref(), request).await;
         }
+
+        self.inner.client.execute(request).await.map_err(|source| {
+            ElevenLabsTTVError::RequestError {
+                source,
+                context: None,
+            }
+        })
     }
 
-    /// Internal method to execute TTV: Create Voice request
-    pub(crate) async fn execute_create_voice(
+    /// Internal method to send a request (see `send`) and parse its JSON response
+    pub(crate) async fn send_json<T: DeserializeOwned>(
         &self,
-        request: TTVCreateVoiceRequest,
-    ) -> Result<TTVCreateVoiceResponse, ElevenLabsTTVError> {
-        let url = format!("{}/text-to-voice", self.base_url);
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<T, ElevenLabsTTVError> {
+        let response = self.send(endpoint, request).await?;
+        parse_json(response).await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("xi-api-key", &self.api_key)
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await?;
+    /// Internal method to send a request (see `send`) and return its untyped JSON response
+    pub(crate) async fn send_raw(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<RawResponse, ElevenLabsTTVError> {
+        let response = self.send(endpoint, request).await?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = parse_json(response).await?;
 
-        if !response.status().is_success() {
-            return Err(ElevenLabsTTVError::ApiError {
-                status: response.status().as_u16(),
-                message: response.text().await.unwrap_or_default(),
+        Ok(RawResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Internal method to report downloaded audio to the metrics sink
+    pub(crate) fn record_audio_bytes(&self, endpoint: &'static str, bytes: u64) {
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.record_audio_bytes(endpoint, bytes);
+        }
+    }
+}
+
+/// Internal helper reading a response sent by `send` and parsing its JSON body
+async fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T, ElevenLabsTTVError> {
+    let context = response.extensions().get::<RequestContext>().cloned();
+    let body = read_body(response, context.as_ref()).await?;
+    parse_body(&body, context)
+}
+
+/// Internal helper reading and logging a response body, with `context` on read errors
+pub(crate) async fn read_body(
+    response: Response,
+    context: Option<&RequestContext>,
+) -> Result<bytes::Bytes, ElevenLabsTTVError> {
+    let body = match response.bytes().await {
+        Ok(body) => body,
+        Err(source) => {
+            let error = ElevenLabsTTVError::RequestError {
+                source,
+                context: None,
+            };
+            return Err(match context {
+                Some(context) => error.with_context(context),
+                None => error,
             });
         }
+    };
 
-        let parse_response = response.json::<TTVCreateVoiceResponse>().await;
+    if let Some(context) = context {
+        logging::response_body(context.endpoint, &body);
+    }
+    Ok(body)
+}
+
+/// Internal helper deserializing a JSON response body, with `context` and an excerpt of the
+/// body on errors
+pub(crate) fn parse_body<T: DeserializeOwned>(
+    body: &[u8],
+    context: Option<RequestContext>,
+) -> Result<T, ElevenLabsTTVError> {
+    serde_json::from_slice(body)
+        .map_err(|source| ElevenLabsTTVError::parse_error(source, body, context))
+}
 
-        match parse_response {
-            Ok(ttv_response) => return Ok(ttv_response),
-            Err(e) => return Err(ElevenLabsTTVError::ParseError(e)),
+/// The `eleven_multilingual_ttv_v2` retry of a design rejected by `eleven_ttv_v3`, without the
+/// v3-only reference audio and prompt strength, or `None` when it would not be valid for v2
+/// (e.g. a reference-audio-only design, which has no description for v2 to work from)
+fn v2_fallback(request: TTVDesignVoiceRequest) -> Option<TTVDesignVoiceRequest> {
+    let fallback = TTVDesignVoiceRequest {
+        model_id: Some(models::elevanlabs_models::ELEVEN_MULTILINGUAL_TTV_V2.to_string()),
+        reference_audio_base64: None,
+        reference_audios: Vec::new(),
+        prompt_strength: None,
+        ..request
+    };
+    fallback.violations().is_empty().then_some(fallback)
+}
+
+/// Internal helper serializing a request into its JSON body, without the fields sent elsewhere
+/// (query string). `extra_body` entries are merged in, overriding modeled fields with the same
+/// name.
+fn json_body<T: serde::Serialize>(
+    request: &T,
+    non_body_fields: &[&str],
+) -> Result<serde_json::Value, ElevenLabsTTVError> {
+    let mut body = serde_json::to_value(request)?;
+    if let Some(body) = body.as_object_mut() {
+        if let Some(serde_json::Value::Object(extra_body)) = body.remove("extra_body") {
+            body.extend(extra_body);
+        }
+        for field in non_body_fields {
+            body.remove(*field);
         }
     }
+    Ok(body)
 }
 
 /// Builder for Text-to-Voice: Design Voice requests
-pub struct TextToVoiceDesignVoiceBuilder {
-    client: ElevenLabsTTVClient,
+pub struct TextToVoiceDesignVoiceBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
     pub voice_description: String,
     pub output_format: Option<String>,
     pub model_id: Option<String>,
@@ -193,11 +846,16 @@ pub struct TextToVoiceDesignVoiceBuilder {
     pub remixing_session_iteration_id: Option<String>,
     pub quality: Option<f32>,
     pub reference_audio_base64: Option<String>,
+    pub reference_audio_file: Option<PathBuf>,
+    pub reference_audios: Vec<ReferenceAudio>,
     pub prompt_strength: Option<f32>,
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
+    pub extra_query: Vec<(String, String)>,
+    pub strict: bool,
 }
 
-impl TextToVoiceDesignVoiceBuilder {
-    fn new(client: ElevenLabsTTVClient, voice_description: String) -> Self {
+impl<'a> TextToVoiceDesignVoiceBuilder<'a> {
+    fn new(client: &'a ElevenLabsTTVClient, voice_description: String) -> Self {
         Self {
             client,
             voice_description,
@@ -213,7 +871,12 @@ impl TextToVoiceDesignVoiceBuilder {
             remixing_session_iteration_id: None,
             quality: None,
             reference_audio_base64: None,
+            reference_audio_file: None,
+            reference_audios: Vec::new(),
             prompt_strength: None,
+            extra_body: serde_json::Map::new(),
+            extra_query: Vec::new(),
+            strict: false,
         }
     }
 
@@ -267,7 +930,7 @@ impl TextToVoiceDesignVoiceBuilder {
     /// High numbers can cause voice to sound artificial or robotic. We recommend to use longer, more detailed prompts at lower Guidance Scale.
     /// >=0 <=100 Defaults to 5
     pub fn guidance_scale(mut self, guidance_scale: u32) -> Self {
-        self.guidance_scale = Some(guidance_scale.into());
+        self.guidance_scale = Some(guidance_scale);
         self
     }
 
@@ -298,7 +961,7 @@ impl TextToVoiceDesignVoiceBuilder {
     /// Higher quality results in better voice output but less variety.
     /// >=-1.0 <=1.0 Default to 0.0
     pub fn quality(mut self, quality: f32) -> Self {
-        self.quality = Some(quality.into());
+        self.quality = Some(quality);
         self
     }
 
@@ -309,23 +972,172 @@ impl TextToVoiceDesignVoiceBuilder {
         self
     }
 
+    /// Reference audio file to use for the voice generation, instead of `reference_audio_base64`.
+    /// With `execute` the file is streamed and base64-encoded on the fly rather than loaded
+    /// into memory (with the `tokio` feature, default); `build`, `execute_raw` and
+    /// `execute_session` load it into the request.
+    /// Only supported when using the eleven_ttv_v3 model.
+    pub fn reference_audio_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.reference_audio_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Add a weighted reference clip, e.g. `.add_reference_audio(ReferenceAudio::new(b64).weight(0.7))`.
+    /// A single clip without a weight is sent as `reference_audio_base64`; otherwise the clips
+    /// are sent as `reference_audios`, for when the design endpoint accepts more than one.
+    /// Cannot be combined with `reference_audio_base64` or `reference_audio_file`.
+    /// Only supported when using the eleven_ttv_v3 model.
+    pub fn add_reference_audio(mut self, reference_audio: ReferenceAudio) -> Self {
+        self.reference_audios.push(reference_audio);
+        self
+    }
+
     /// Controls the balance of prompt versus reference audio when generating voice samples.
     /// 0 means almost no prompt influence, 1 means almost no reference audio influence.
     /// Only supported when using the eleven_ttv_v3 model and providing reference audio.
     /// >=0 <=1 Default to 0
     pub fn prompt_strength(mut self, prompt_strength: f32) -> Self {
-        self.prompt_strength = Some(prompt_strength.into());
+        self.prompt_strength = Some(prompt_strength);
+        self
+    }
+
+    /// Fail with a `ValidationError` if `lint_description` has any warning about the voice
+    /// description, e.g. a missing age or accent cue. Defaults to false
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Configure the builder with a closure, e.g. for conditional parameters:
+    /// `.configure(|b| if hq { b.quality(1.0) } else { b })`
+    pub fn configure<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        f(self)
+    }
+
+    /// Apply a setter only when `value` is `Some`, e.g. `.apply(cfg.seed, Self::seed)`
+    pub fn apply<T, F: FnOnce(Self, T) -> Self>(self, value: Option<T>, f: F) -> Self {
+        match value {
+            Some(value) => f(self, value),
+            None => self,
+        }
+    }
+
+    /// Set quality, guidance_scale and loudness from a preset.
+    /// Setters called afterwards override the preset's value.
+    pub fn preset(mut self, preset: DesignPreset) -> Self {
+        self.quality = Some(preset.quality());
+        self.guidance_scale = Some(preset.guidance_scale());
+        self.loudness = Some(preset.loudness());
+        self
+    }
+
+    /// Add a body parameter not (yet) modeled by this crate, e.g. one ElevenLabs just shipped.
+    /// It is sent as-is and overrides a modeled parameter with the same name.
+    pub fn extra_body<S: Into<String>>(mut self, key: S, value: serde_json::Value) -> Self {
+        self.extra_body.insert(key.into(), value);
+        self
+    }
+
+    /// Add a query parameter not (yet) modeled by this crate, sent as-is
+    pub fn extra_query<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.extra_query.push((key.into(), value.into()));
         self
     }
 
     /// Execute the Text-to-Voice: Design Voice request
     pub async fn execute(self) -> Result<TTVDesignVoiceResponse, ElevenLabsTTVError> {
+        #[cfg(feature = "tokio")]
+        if self.reference_audio_base64.is_none()
+            && let Some(path) = self.reference_audio_file.clone()
+        {
+            if !self.reference_audios.is_empty() {
+                return Err(ElevenLabsTTVError::ValidationError(
+                    "set either reference_audios or reference_audio_file, not both".to_string(),
+                ));
+            }
+            let (client, request) = Self {
+                reference_audio_file: None,
+                ..self
+            }
+            .into_unvalidated_parts()?;
+            validation::into_result(request.violations_with_reference_audio(true))?;
+            if self.strict {
+                voice_description::check_lints(&request.voice_description)?;
+            }
+            let result = client.execute_design_voice_streamed(&request, &path).await;
+            return client.with_model_fallback(result, request).await;
+        }
+
+        let (client, request) = self.into_parts()?;
+
+        client.execute_design_voice(request).await
+    }
+
+    /// Execute the Text-to-Voice: Design Voice request and return the untyped JSON response,
+    /// with its status and headers. Useful to debug schema mismatches or read fields
+    /// not modeled by `TTVDesignVoiceResponse` yet.
+    pub async fn execute_raw(self) -> Result<RawResponse, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+
+        client
+            .send_raw(
+                "text-to-voice/design",
+                client.design_voice_request(&request)?,
+            )
+            .await
+    }
+
+    /// Execute the Text-to-Voice: Design Voice request and keep the request alongside its previews
+    ///
+    /// The returned `DesignSession` can be saved to disk and loaded later to finish voice selection
+    /// without regenerating previews.
+    pub async fn execute_session(self) -> Result<DesignSession, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+        let response = client.execute_design_voice(request.clone()).await?;
+
+        Ok(DesignSession::new(request, response))
+    }
+
+    /// Build the fully-defaulted, validated request without executing it,
+    /// e.g. to log, serialize or store it for `ElevenLabsTTVClient::execute_design_voice`.
+    pub fn build(self) -> Result<TTVDesignVoiceRequest, ElevenLabsTTVError> {
+        let (_, request) = self.into_parts()?;
+        Ok(request)
+    }
+
+    /// Split the builder into its client and the fully-defaulted, validated request
+    fn into_parts(
+        self,
+    ) -> Result<(&'a ElevenLabsTTVClient, TTVDesignVoiceRequest), ElevenLabsTTVError> {
+        let strict = self.strict;
+        let (client, request) = self.into_unvalidated_parts()?;
+        request.validate()?;
+        if strict {
+            voice_description::check_lints(&request.voice_description)?;
+        }
+
+        Ok((client, request))
+    }
+
+    /// `into_parts`, without validating the request
+    fn into_unvalidated_parts(
+        self,
+    ) -> Result<(&'a ElevenLabsTTVClient, TTVDesignVoiceRequest), ElevenLabsTTVError> {
+        let mut reference_audios = self.reference_audios;
+        let mut reference_audio_base64 = self.reference_audio_base64;
+        if reference_audio_base64.is_none()
+            && self.reference_audio_file.is_none()
+            && let [ReferenceAudio { weight: None, .. }] = reference_audios.as_slice()
+        {
+            reference_audio_base64 = reference_audios.pop().map(|clip| clip.audio_base64);
+        }
+
         let request = TTVDesignVoiceRequest {
             voice_description: self.voice_description,
             model_id: Some(self.model_id.unwrap_or_else(|| {
                 models::elevanlabs_models::ELEVEN_MULTILINGUAL_TTV_V2.to_string()
             })), // Default to: eleven_multilingual_ttv_v2
-            output_format: None,
+            output_format: self.output_format,
             text: self.text.clone().or(None),
             auto_generate_text: if self.text.is_some() && self.auto_generate_text.is_some() {
                 Some(false)
@@ -339,27 +1151,129 @@ impl TextToVoiceDesignVoiceBuilder {
             remixing_session_id: self.remixing_session_id.or(None),
             remixing_session_iteration_id: self.remixing_session_iteration_id.or(None),
             quality: self.quality.or(None),
-            reference_audio_base64: self.reference_audio_base64.or(None),
+            reference_audio_base64: match (reference_audio_base64, self.reference_audio_file) {
+                (Some(_), Some(_)) => {
+                    return Err(ElevenLabsTTVError::ValidationError(
+                        "set either reference_audio_base64 or reference_audio_file, not both"
+                            .to_string(),
+                    ));
+                }
+                (None, Some(_)) if !reference_audios.is_empty() => {
+                    return Err(ElevenLabsTTVError::ValidationError(
+                        "set either reference_audios or reference_audio_file, not both".to_string(),
+                    ));
+                }
+                (None, Some(path)) => Some(reference_audio::encode_file(&path)?),
+                (reference_audio_base64, None) => reference_audio_base64,
+            },
+            reference_audios,
             prompt_strength: self.prompt_strength.or(None),
+            extra_body: self.extra_body,
+            extra_query: self.extra_query,
         };
 
-        self.client.execute_design_voice(request).await
+        Ok((self.client, request))
+    }
+}
+
+/// Lists the parameters set on the builder. Never includes the API key,
+/// and reference audio is summarized by its size.
+impl fmt::Display for TextToVoiceDesignVoiceBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "design_voice(voice_description={:?}",
+            self.voice_description
+        )?;
+        if let Some(output_format) = &self.output_format {
+            write!(f, ", output_format={:?}", output_format)?;
+        }
+        if let Some(model_id) = &self.model_id {
+            write!(f, ", model_id={:?}", model_id)?;
+        }
+        if let Some(text) = &self.text {
+            write!(f, ", text={:?}", text)?;
+        }
+        if let Some(auto_generate_text) = self.auto_generate_text {
+            write!(f, ", auto_generate_text={}", auto_generate_text)?;
+        }
+        if let Some(loudness) = self.loudness {
+            write!(f, ", loudness={}", loudness)?;
+        }
+        if let Some(seed) = self.seed {
+            write!(f, ", seed={}", seed)?;
+        }
+        if let Some(guidance_scale) = self.guidance_scale {
+            write!(f, ", guidance_scale={}", guidance_scale)?;
+        }
+        if let Some(stream_previews) = self.stream_previews {
+            write!(f, ", stream_previews={}", stream_previews)?;
+        }
+        if let Some(remixing_session_id) = &self.remixing_session_id {
+            write!(f, ", remixing_session_id={:?}", remixing_session_id)?;
+        }
+        if let Some(remixing_session_iteration_id) = &self.remixing_session_iteration_id {
+            write!(
+                f,
+                ", remixing_session_iteration_id={:?}",
+                remixing_session_iteration_id
+            )?;
+        }
+        if let Some(quality) = self.quality {
+            write!(f, ", quality={}", quality)?;
+        }
+        if let Some(reference_audio_base64) = &self.reference_audio_base64 {
+            write!(
+                f,
+                ", reference_audio_base64={}",
+                redact::redact_base64(reference_audio_base64)
+            )?;
+        }
+        if let Some(reference_audio_file) = &self.reference_audio_file {
+            write!(f, ", reference_audio_file={:?}", reference_audio_file)?;
+        }
+        for reference_audio in &self.reference_audios {
+            write!(
+                f,
+                ", reference_audio={}",
+                redact::redact_base64(&reference_audio.audio_base64)
+            )?;
+            if let Some(weight) = reference_audio.weight {
+                write!(f, " (weight {})", weight)?;
+            }
+        }
+        if let Some(prompt_strength) = self.prompt_strength {
+            write!(f, ", prompt_strength={}", prompt_strength)?;
+        }
+        if !self.extra_body.is_empty() {
+            write!(
+                f,
+                ", extra_body={:?}",
+                self.extra_body.keys().collect::<Vec<_>>()
+            )?;
+        }
+        if !self.extra_query.is_empty() {
+            write!(f, ", extra_query={:?}", self.extra_query)?;
+        }
+        write!(f, ")")
     }
 }
 
 /// Builder for Text-to-Voice: Create Voice requests
-pub struct TextToVoiceCreateVoiceBuilder {
-    client: ElevenLabsTTVClient,
+pub struct TextToVoiceCreateVoiceBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
     pub voice_name: String,
     pub voice_description: String,
     pub generated_voice_id: String,
     pub labels: Option<String>,
-    pub played_not_selected_voice_ids: Option<String>,
+    pub played_not_selected_voice_ids: Option<Vec<String>>,
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
+    pub extra_query: Vec<(String, String)>,
 }
 
-impl TextToVoiceCreateVoiceBuilder {
+impl<'a> TextToVoiceCreateVoiceBuilder<'a> {
     fn new(
-        client: ElevenLabsTTVClient,
+        client: &'a ElevenLabsTTVClient,
         voice_name: String,
         voice_description: String,
         generated_voice_id: String,
@@ -371,6 +1285,8 @@ impl TextToVoiceCreateVoiceBuilder {
             generated_voice_id,
             labels: None,
             played_not_selected_voice_ids: None,
+            extra_body: serde_json::Map::new(),
+            extra_query: Vec::new(),
         }
     }
 
@@ -381,25 +1297,144 @@ impl TextToVoiceCreateVoiceBuilder {
     }
 
     /// List of voice ids that the user has played but not selected. Used for RLHF.
-    pub fn played_not_selected_voice_ids<S: Into<String>>(
-        mut self,
-        played_not_selected_voice_ids: S,
-    ) -> Self {
-        self.played_not_selected_voice_ids = Some(played_not_selected_voice_ids.into());
+    pub fn played_not_selected_voice_ids<I, S>(mut self, played_not_selected_voice_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.played_not_selected_voice_ids = Some(
+            played_not_selected_voice_ids
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        );
+        self
+    }
+
+    /// Configure the builder with a closure, e.g. for conditional parameters:
+    /// `.configure(|b| if tag { b.labels(labels) } else { b })`
+    pub fn configure<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+        f(self)
+    }
+
+    /// Apply a setter only when `value` is `Some`, e.g. `.apply(cfg.labels, Self::labels)`
+    pub fn apply<T, F: FnOnce(Self, T) -> Self>(self, value: Option<T>, f: F) -> Self {
+        match value {
+            Some(value) => f(self, value),
+            None => self,
+        }
+    }
+
+    /// Populate `played_not_selected_voice_ids` from an `AuditionTracker`,
+    /// excluding the voice being created. Does nothing if no other preview was played.
+    pub fn audition(mut self, tracker: &AuditionTracker) -> Self {
+        let played_not_selected = tracker.played_not_selected(&self.generated_voice_id);
+        if !played_not_selected.is_empty() {
+            self.played_not_selected_voice_ids = Some(played_not_selected);
+        }
+        self
+    }
+
+    /// Add a body parameter not (yet) modeled by this crate, e.g. one ElevenLabs just shipped.
+    /// It is sent as-is and overrides a modeled parameter with the same name.
+    pub fn extra_body<S: Into<String>>(mut self, key: S, value: serde_json::Value) -> Self {
+        self.extra_body.insert(key.into(), value);
+        self
+    }
+
+    /// Add a query parameter not (yet) modeled by this crate, sent as-is
+    pub fn extra_query<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.extra_query.push((key.into(), value.into()));
         self
     }
 
     /// Execute the Text-to-Voice: Create Voice request
     pub async fn execute(self) -> Result<TTVCreateVoiceResponse, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+
+        client.execute_create_voice(request).await
+    }
+
+    /// Execute the Text-to-Voice: Create Voice request and return the untyped JSON response,
+    /// with its status and headers.
+    pub async fn execute_raw(self) -> Result<RawResponse, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+
+        client
+            .send_raw("text-to-voice", client.create_voice_request(&request)?)
+            .await
+    }
+
+    /// Execute the Create Voice request only if no voice with the same name (and labels)
+    /// exists yet, otherwise return the existing voice.
+    ///
+    /// Makes retried jobs idempotent instead of creating duplicate voices and burning voice slots.
+    /// Costs one extra List Voices call.
+    pub async fn execute_if_absent(self) -> Result<TTVCreateVoiceResponse, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+
+        if let Some(existing) = client.find_matching_voice(&request).await? {
+            return Ok(existing);
+        }
+
+        client.execute_create_voice(request).await
+    }
+
+    /// Build the validated request without executing it,
+    /// e.g. to log, serialize or store it for `ElevenLabsTTVClient::execute_create_voice`.
+    pub fn build(self) -> Result<TTVCreateVoiceRequest, ElevenLabsTTVError> {
+        let (_, request) = self.into_parts()?;
+        Ok(request)
+    }
+
+    /// Split the builder into its client and the validated request
+    fn into_parts(
+        self,
+    ) -> Result<(&'a ElevenLabsTTVClient, TTVCreateVoiceRequest), ElevenLabsTTVError> {
         let request = TTVCreateVoiceRequest {
             voice_name: self.voice_name,
             generated_voice_id: self.generated_voice_id,
             voice_description: self.voice_description,
             labels: self.labels.or(None),
             played_not_selected_voice_ids: self.played_not_selected_voice_ids.or(None),
+            extra_body: self.extra_body,
+            extra_query: self.extra_query,
         };
+        request.validate()?;
 
-        self.client.execute_create_voice(request).await
+        Ok((self.client, request))
+    }
+}
+
+/// Lists the parameters set on the builder. Never includes the API key.
+impl fmt::Display for TextToVoiceCreateVoiceBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_voice(voice_name={:?}, voice_description={:?}, generated_voice_id={:?}",
+            self.voice_name, self.voice_description, self.generated_voice_id
+        )?;
+        if let Some(labels) = &self.labels {
+            write!(f, ", labels={:?}", labels)?;
+        }
+        if let Some(played_not_selected_voice_ids) = &self.played_not_selected_voice_ids {
+            write!(
+                f,
+                ", played_not_selected_voice_ids={:?}",
+                played_not_selected_voice_ids
+            )?;
+        }
+        if !self.extra_body.is_empty() {
+            write!(
+                f,
+                ", extra_body={:?}",
+                self.extra_body.keys().collect::<Vec<_>>()
+            )?;
+        }
+        if !self.extra_query.is_empty() {
+            write!(f, ", extra_query={:?}", self.extra_query)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -410,7 +1445,127 @@ mod tests {
     #[tokio::test]
     async fn test_client_creation() {
         let client = ElevenLabsTTVClient::new("test-key");
-        assert_eq!(client.api_key, "test-key");
+        assert_eq!(client.inner.api_keys.current().1, "test-key");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_recorded_for_failed_request() {
+        let metrics = Arc::new(metrics::InMemoryMetrics::new());
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url("http://127.0.0.1:9")
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        let result = client.design_voice("Soft-spoken librarian").execute().await;
+        assert!(result.is_err());
+
+        let design = &metrics.snapshot()["text-to-voice/design"];
+        assert_eq!(design.requests, 1);
+        assert_eq!(design.errors, 1);
+    }
+
+    #[test]
+    fn test_clones_share_state() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(&client.inner, &clone.inner));
+
+        // Builders borrow the client instead of cloning it
+        let builder = client.design_voice("Raspy old wizard, slow and wise");
+        assert!(std::ptr::eq(builder.client, &client));
+    }
+
+    #[test]
+    fn test_url_construction() {
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", "https://proxy.corp/elevenlabs/v1/");
+        assert_eq!(
+            client.url(&["text-to-voice", "design"]).unwrap().as_str(),
+            "https://proxy.corp/elevenlabs/v1/text-to-voice/design"
+        );
+        assert_eq!(
+            client.url(&["text-to-voice", "a/b c"]).unwrap().as_str(),
+            "https://proxy.corp/elevenlabs/v1/text-to-voice/a%2Fb%20c"
+        );
+
+        let client = ElevenLabsTTVClient::with_base_url("test-key", "not a url");
+        assert!(client.url(&["text-to-voice"]).is_err());
+    }
+
+    #[test]
+    fn test_extra_body_and_query() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let (_, request) = client
+            .design_voice("Booming stadium announcer")
+            .seed(1)
+            .output_format("pcm_16000")
+            .extra_body("brand_new_param", serde_json::json!(true))
+            .extra_body("seed", serde_json::json!(9))
+            .extra_query("enable_logging", "false")
+            .into_parts()
+            .unwrap();
+
+        let body = json_body(&request, &["output_format", "extra_query"]).unwrap();
+        assert_eq!(body["brand_new_param"], true);
+        assert_eq!(body["seed"], 9);
+        assert!(body.get("output_format").is_none());
+        assert!(body.get("extra_query").is_none());
+        assert!(body.get("extra_body").is_none());
+        assert_eq!(
+            request.extra_query,
+            vec![("enable_logging".to_string(), "false".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_request_rejects_unknown_fields() {
+        let error = serde_json::from_value::<TTVDesignVoiceRequest>(serde_json::json!({
+            "voice_description": "Booming stadium announcer",
+            "sed": 1
+        }))
+        .unwrap_err();
+        assert!(
+            error.to_string().contains("unknown field `sed`"),
+            "{}",
+            error
+        );
+        assert!(
+            serde_json::from_value::<TTVCreateVoiceRequest>(serde_json::json!({
+                "voice_name": "Announcer",
+                "voice_description": "Booming stadium announcer",
+                "generated_voice_id": "gen-1",
+                "lables": "sports"
+            }))
+            .is_err()
+        );
+
+        let request: TTVDesignVoiceRequest = serde_json::from_value(serde_json::json!({
+            "voice_description": "Booming stadium announcer",
+            "extra_body": {"brand_new_param": true}
+        }))
+        .unwrap();
+        assert_eq!(request.extra_body["brand_new_param"], true);
+        let body = json_body(&request, &["output_format", "extra_query"]).unwrap();
+        assert_eq!(body["brand_new_param"], true);
+    }
+
+    #[test]
+    fn test_debug_and_display_redact_secrets() {
+        let client = ElevenLabsTTVClient::new("sk_secret_api_key_1234");
+        let debug = format!("{:?}", client);
+        assert!(!debug.contains("sk_secret_api_key"));
+        assert!(debug.contains("****1234"));
+
+        let builder = client
+            .design_voice("Husky jazz singer")
+            .seed(7)
+            .reference_audio_base64("aGVsbG8=");
+        let display = builder.to_string();
+        assert_eq!(
+            display,
+            "design_voice(voice_description=\"Husky jazz singer\", seed=7, reference_audio_base64=<base64, 5 bytes>)"
+        );
     }
 
     #[test]
@@ -452,4 +1607,79 @@ mod tests {
         assert_eq!(builder.generated_voice_id, "generated-voice-id".to_string());
         assert_eq!(builder.labels, Some("voice-labels".to_string()));
     }
+
+    #[test]
+    fn test_configure_and_apply() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let high_quality = true;
+        let seed: Option<u32> = None;
+        let builder = client
+            .design_voice("Calm, deep-voiced narrator")
+            .configure(|b| if high_quality { b.quality(1.0) } else { b })
+            .apply(seed, TextToVoiceDesignVoiceBuilder::seed)
+            .apply(Some(7), TextToVoiceDesignVoiceBuilder::guidance_scale);
+
+        assert_eq!(builder.quality, Some(1.0));
+        assert_eq!(builder.seed, None);
+        assert_eq!(builder.guidance_scale, Some(7));
+    }
+
+    #[test]
+    fn test_add_reference_audio() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let mp3 = "SUQzBAAAAAAAAA==";
+
+        let single = client
+            .design_voice("Calm, deep-voiced narrator")
+            .add_reference_audio(ReferenceAudio::new(mp3))
+            .build()
+            .unwrap();
+        assert_eq!(single.reference_audio_base64.as_deref(), Some(mp3));
+        assert!(single.reference_audios.is_empty());
+
+        let weighted = client
+            .design_voice("Calm, deep-voiced narrator")
+            .add_reference_audio(ReferenceAudio::new(mp3).weight(0.7))
+            .add_reference_audio(ReferenceAudio::new(mp3).weight(0.3))
+            .build()
+            .unwrap();
+        let body = serde_json::to_value(&weighted).unwrap();
+        assert_eq!(body["reference_audios"][1]["weight"], 0.3f32 as f64);
+        assert!(body["reference_audio_base64"].is_null());
+
+        let weighted_single = client
+            .design_voice("Calm, deep-voiced narrator")
+            .add_reference_audio(ReferenceAudio::new(mp3).weight(0.7))
+            .build()
+            .unwrap();
+        assert_eq!(weighted_single.reference_audio_base64, None);
+        assert_eq!(weighted_single.reference_audios.len(), 1);
+
+        let invalid = client
+            .design_voice("Calm, deep-voiced narrator")
+            .add_reference_audio(ReferenceAudio::new(mp3).weight(1.5))
+            .add_reference_audio(ReferenceAudio::new(mp3))
+            .build();
+        assert!(invalid.is_err());
+
+        let error = client
+            .design_voice("Calm, deep-voiced narrator")
+            .reference_audio_base64(mp3)
+            .add_reference_audio(ReferenceAudio::new(mp3))
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(&error, ElevenLabsTTVError::ValidationError(msg) if msg.contains("not both")),
+            "{}",
+            error
+        );
+        assert!(
+            client
+                .design_voice("Calm, deep-voiced narrator")
+                .reference_audio_file("voice.mp3")
+                .add_reference_audio(ReferenceAudio::new(mp3))
+                .build()
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,62 @@
+//! Holding a `max_concurrent_requests` slot until the response body has been read
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use reqwest::{Response, ResponseBuilderExt};
+use tokio::sync::OwnedSemaphorePermit;
+
+/// Move `permit` into the body of `response`, so it is released once the body has been read
+/// to the end or dropped, whichever way it is consumed (`bytes`, `chunk`, `bytes_stream`, ...)
+///
+/// Keeping it in the response extensions is not enough: reqwest drops them when the body is
+/// taken out of the response.
+pub(crate) fn hold_permit(response: Response, permit: OwnedSemaphorePermit) -> Response {
+    let url = response.url().clone();
+    let (mut parts, body) = http::Response::from(response).into_parts();
+    // The conversion drops the URL, which reqwest keeps in a private extension
+    let (with_url, _) = http::Response::builder()
+        .url(url)
+        .body(())
+        .expect("an empty response is valid")
+        .into_parts();
+    parts.extensions.extend(with_url.extensions);
+
+    let body = PermitBody {
+        inner: body,
+        permit: Some(permit),
+    };
+    http::Response::from_parts(parts, reqwest::Body::wrap(body)).into()
+}
+
+struct PermitBody {
+    inner: reqwest::Body,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Body for PermitBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        let frame = Pin::new(&mut this.inner).poll_frame(cx);
+        if let Poll::Ready(None | Some(Err(_))) = frame {
+            this.permit = None;
+        }
+        frame
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
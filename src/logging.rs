@@ -0,0 +1,159 @@
+//! `log` records for API calls (`log` feature), for projects not using tracing
+//!
+//! Records use the `elevenlabs_ttv` target: debug when a request starts, info when it
+//! completes, warn when it fails or is retried. Request and JSON response bodies are logged at
+//! trace level, with base64 audio replaced by its size and hash (see
+//! `redact::redact_json_body`). Never includes API keys. Without the feature these are no-ops.
+
+use std::time::Duration;
+
+use reqwest::Method;
+
+use crate::{ElevenLabsTTVError, RetryInfo};
+
+const TARGET: &str = "elevenlabs_ttv";
+
+pub(crate) fn request_started(endpoint: &'static str, method: &Method, attempt: usize) {
+    #[cfg(feature = "log")]
+    log::debug!(target: TARGET, "{} {} started (attempt {})", method, endpoint, attempt);
+    #[cfg(not(feature = "log"))]
+    let _ = (TARGET, endpoint, method, attempt);
+}
+
+/// `body` is `None` for streamed bodies (e.g. multipart uploads), which are not logged
+pub(crate) fn request_body(endpoint: &'static str, method: &Method, body: Option<&[u8]>) {
+    #[cfg(feature = "log")]
+    if log::log_enabled!(target: TARGET, log::Level::Trace) {
+        match body {
+            Some(body) => log::trace!(
+                target: TARGET,
+                "{} {} request body: {}",
+                method,
+                endpoint,
+                crate::redact::redact_json_body(body)
+            ),
+            None => log::trace!(target: TARGET, "{} {} request body: <streamed>", method, endpoint),
+        }
+    }
+    #[cfg(not(feature = "log"))]
+    let _ = (endpoint, method, body);
+}
+
+pub(crate) fn response_body(endpoint: &'static str, body: &[u8]) {
+    #[cfg(feature = "log")]
+    if log::log_enabled!(target: TARGET, log::Level::Trace) {
+        log::trace!(
+            target: TARGET,
+            "{} response body: {}",
+            endpoint,
+            crate::redact::redact_json_body(body)
+        );
+    }
+    #[cfg(not(feature = "log"))]
+    let _ = (endpoint, body);
+}
+
+pub(crate) fn request_completed(
+    endpoint: &'static str,
+    method: &Method,
+    status: u16,
+    latency: Duration,
+) {
+    #[cfg(feature = "log")]
+    if (200..300).contains(&status) || status == 304 {
+        log::info!(target: TARGET, "{} {} completed with {} in {:?}", method, endpoint, status, latency);
+    } else {
+        log::warn!(
+            target: TARGET,
+            "{} {} failed with {} ({}) in {:?}",
+            method,
+            endpoint,
+            status,
+            classify_status(status),
+            latency
+        );
+    }
+    #[cfg(not(feature = "log"))]
+    let _ = (endpoint, method, status, latency);
+}
+
+pub(crate) fn request_failed(endpoint: &'static str, method: &Method, error: &ElevenLabsTTVError) {
+    #[cfg(feature = "log")]
+    {
+        let kind = match error {
+            ElevenLabsTTVError::RequestError { source: e, .. } if e.is_timeout() => "timeout",
+            ElevenLabsTTVError::RequestError { source: e, .. } if e.is_connect() => {
+                "connection error"
+            }
+            ElevenLabsTTVError::MiddlewareError(_) => "middleware error",
+            _ => "network error",
+        };
+        log::warn!(target: TARGET, "{} {} failed ({}): {}", method, endpoint, kind, error);
+    }
+    #[cfg(not(feature = "log"))]
+    let _ = (endpoint, method, error);
+}
+
+pub(crate) fn request_retried(retry: &RetryInfo) {
+    #[cfg(feature = "log")]
+    log::warn!(
+        target: TARGET,
+        "{} retrying (attempt {}): {}",
+        retry.endpoint,
+        retry.attempt,
+        retry.reason
+    );
+    #[cfg(not(feature = "log"))]
+    let _ = retry;
+}
+
+pub(crate) fn retry_budget_exhausted(endpoint: &'static str) {
+    #[cfg(feature = "log")]
+    log::warn!(target: TARGET, "{} not retried: retry budget exhausted", endpoint);
+    #[cfg(not(feature = "log"))]
+    let _ = endpoint;
+}
+
+pub(crate) fn circuit_opened(consecutive_failures: u32, open_duration: Duration) {
+    #[cfg(feature = "log")]
+    log::warn!(
+        target: TARGET,
+        "circuit breaker opened after {} consecutive failures, failing requests for {:?}",
+        consecutive_failures,
+        open_duration
+    );
+    #[cfg(not(feature = "log"))]
+    let _ = (consecutive_failures, open_duration);
+}
+
+pub(crate) fn model_deprecated(model_id: &str) {
+    #[cfg(feature = "log")]
+    log::warn!(target: TARGET, "model {} is deprecated and will be removed", model_id);
+    #[cfg(not(feature = "log"))]
+    let _ = model_id;
+}
+
+/// Short description of what an error status means for the caller
+#[cfg_attr(not(feature = "log"), allow(dead_code))]
+fn classify_status(status: u16) -> &'static str {
+    match status {
+        401 | 403 => "authentication",
+        402 => "quota exceeded",
+        422 | 400 => "invalid request",
+        429 => "rate limited",
+        500..=599 => "server error",
+        _ => "client error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_status() {
+        assert_eq!(classify_status(429), "rate limited");
+        assert_eq!(classify_status(503), "server error");
+        assert_eq!(classify_status(401), "authentication");
+    }
+}
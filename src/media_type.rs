@@ -0,0 +1,118 @@
+//! Media types of preview audio, e.g. "audio/mpeg"
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Media type of preview audio, used to pick file extensions when saving previews
+///
+/// Parsing is case-insensitive and accepts common aliases ("audio/mp3", "audio/x-wav"), which
+/// serialize as the canonical type. Other types are kept as `Other`, and serialize back
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum MediaType {
+    /// "audio/mpeg", the `mp3_*` output formats
+    AudioMpeg,
+    /// "audio/wav"
+    AudioWav,
+    /// "audio/ogg"
+    AudioOgg,
+    Other(String),
+}
+
+impl MediaType {
+    /// The media type, e.g. "audio/mpeg"
+    pub fn as_str(&self) -> &str {
+        match self {
+            MediaType::AudioMpeg => "audio/mpeg",
+            MediaType::AudioWav => "audio/wav",
+            MediaType::AudioOgg => "audio/ogg",
+            MediaType::Other(media_type) => media_type,
+        }
+    }
+
+    /// File extension for the audio, e.g. "mp3" for "audio/mpeg", or "bin" for unknown types
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            MediaType::AudioMpeg => "mp3",
+            MediaType::AudioWav => "wav",
+            MediaType::AudioOgg => "ogg",
+            MediaType::Other(media_type) => match media_type.to_ascii_lowercase().as_str() {
+                "audio/opus" => "opus",
+                "audio/flac" => "flac",
+                "audio/pcm" | "audio/l16" => "pcm",
+                "audio/basic" | "audio/mulaw" | "audio/x-mulaw" => "ulaw",
+                "audio/alaw" | "audio/x-alaw" => "alaw",
+                _ => "bin",
+            },
+        }
+    }
+}
+
+impl FromStr for MediaType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(media_type: &str) -> Result<Self, Self::Err> {
+        Ok(match media_type.to_ascii_lowercase().as_str() {
+            "audio/mpeg" | "audio/mp3" => MediaType::AudioMpeg,
+            "audio/wav" | "audio/x-wav" | "audio/wave" => MediaType::AudioWav,
+            "audio/ogg" => MediaType::AudioOgg,
+            _ => MediaType::Other(media_type.to_string()),
+        })
+    }
+}
+
+impl From<&str> for MediaType {
+    fn from(media_type: &str) -> Self {
+        let Ok(media_type) = media_type.parse();
+        media_type
+    }
+}
+
+impl From<String> for MediaType {
+    fn from(media_type: String) -> Self {
+        media_type.as_str().into()
+    }
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for MediaType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for MediaType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_type_round_trip() {
+        assert_eq!(MediaType::from("audio/MP3"), MediaType::AudioMpeg);
+        assert_eq!(MediaType::AudioMpeg.file_extension(), "mp3");
+        assert_eq!(
+            serde_json::to_string(&MediaType::AudioWav).unwrap(),
+            "\"audio/wav\""
+        );
+
+        let opus: MediaType = serde_json::from_str("\"audio/opus\"").unwrap();
+        assert_eq!(opus, MediaType::Other("audio/opus".to_string()));
+        assert_eq!(opus.file_extension(), "opus");
+        assert_eq!(opus.to_string(), "audio/opus");
+        assert_eq!(MediaType::from("video/mp4").file_extension(), "bin");
+    }
+}
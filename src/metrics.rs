@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Method;
+
+/// Outcome of a single HTTP request made by the client
+#[derive(Debug, Clone)]
+pub struct RequestMetrics {
+    /// Endpoint path template, e.g. "text-to-voice/design"
+    pub endpoint: &'static str,
+    /// HTTP method used
+    pub method: Method,
+    /// Response status code, `None` if no response was received (network error, timeout, ...)
+    pub status: Option<u16>,
+    /// Time until the response headers were received (or the request failed)
+    pub latency: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether the request got a successful (2xx) or 304 Not Modified response
+    pub fn is_success(&self) -> bool {
+        self.status
+            .is_some_and(|status| (200..300).contains(&status) || status == 304)
+    }
+}
+
+/// Receives metrics for every request made by the client
+///
+/// Attach one with `ElevenLabsTTVClient::builder(api_key).metrics(sink)` to forward
+/// request counts, latencies, status codes and downloaded audio to your metrics system.
+pub trait MetricsSink: Send + Sync {
+    /// Called once per HTTP request
+    fn record_request(&self, metrics: &RequestMetrics);
+
+    /// Called when audio was downloaded from an endpoint (decoded size in bytes)
+    fn record_audio_bytes(&self, endpoint: &'static str, bytes: u64) {
+        let _ = (endpoint, bytes);
+    }
+}
+
+/// Aggregated metrics for one endpoint, see `InMemoryMetrics`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EndpointMetrics {
+    pub requests: u64,
+    pub errors: u64,
+    pub status_counts: HashMap<u16, u64>,
+    pub total_latency: Duration,
+    pub audio_bytes: u64,
+}
+
+impl EndpointMetrics {
+    /// Average latency over all recorded requests
+    pub fn average_latency(&self) -> Duration {
+        if self.requests == 0 {
+            Duration::ZERO
+        } else {
+            self.total_latency / self.requests as u32
+        }
+    }
+}
+
+/// Simple `MetricsSink` aggregating metrics per endpoint in memory
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics {
+    endpoints: Mutex<HashMap<&'static str, EndpointMetrics>>,
+}
+
+impl InMemoryMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy of the metrics recorded so far, keyed by endpoint
+    pub fn snapshot(&self) -> HashMap<&'static str, EndpointMetrics> {
+        self.endpoints.lock().unwrap().clone()
+    }
+}
+
+impl MetricsSink for InMemoryMetrics {
+    fn record_request(&self, metrics: &RequestMetrics) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let entry = endpoints.entry(metrics.endpoint).or_default();
+
+        entry.requests += 1;
+        entry.total_latency += metrics.latency;
+        if !metrics.is_success() {
+            entry.errors += 1;
+        }
+        if let Some(status) = metrics.status {
+            *entry.status_counts.entry(status).or_default() += 1;
+        }
+    }
+
+    fn record_audio_bytes(&self, endpoint: &'static str, bytes: u64) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        endpoints.entry(endpoint).or_default().audio_bytes += bytes;
+    }
+}
+
+/// Size of the data encoded in a base64 string, without decoding it
+pub(crate) fn decoded_base64_len(encoded: &str) -> u64 {
+    let padding = encoded.bytes().rev().take_while(|&b| b == b'=').count();
+    ((encoded.len() / 4 * 3).saturating_sub(padding)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_metrics() {
+        let metrics = InMemoryMetrics::new();
+        metrics.record_request(&RequestMetrics {
+            endpoint: "text-to-voice/design",
+            method: Method::POST,
+            status: Some(200),
+            latency: Duration::from_millis(300),
+        });
+        metrics.record_request(&RequestMetrics {
+            endpoint: "text-to-voice/design",
+            method: Method::POST,
+            status: Some(429),
+            latency: Duration::from_millis(100),
+        });
+        metrics.record_audio_bytes("text-to-voice/design", 1024);
+
+        let snapshot = metrics.snapshot();
+        let design = &snapshot["text-to-voice/design"];
+        assert_eq!(design.requests, 2);
+        assert_eq!(design.errors, 1);
+        assert_eq!(design.status_counts[&429], 1);
+        assert_eq!(design.average_latency(), Duration::from_millis(200));
+        assert_eq!(design.audio_bytes, 1024);
+    }
+
+    #[test]
+    fn test_decoded_base64_len() {
+        assert_eq!(decoded_base64_len("aGVsbG8="), 5);
+        assert_eq!(decoded_base64_len("aGVsbG8h"), 6);
+        assert_eq!(decoded_base64_len(""), 0);
+    }
+}
@@ -2,4 +2,9 @@
 pub mod elevanlabs_models {
     pub const ELEVEN_MULTILINGUAL_TTV_V2: &str = "eleven_multilingual_ttv_v2";
     pub const ELEVEN_TTV_V3: &str = "eleven_ttv_v3";
+    /// Text-to-Speech model used by `ElevenLabsTTVClient::speak`
+    pub const ELEVEN_MULTILINGUAL_V2: &str = "eleven_multilingual_v2";
+
+    /// Models still served but scheduled for removal, reported as `ModelStatus::Deprecated`
+    pub const DEPRECATED_MODELS: &[&str] = &["eleven_monolingual_v1", "eleven_multilingual_v1"];
 }
@@ -0,0 +1,181 @@
+//! Design a voice once per language, with preview texts from a bundled sample-text table,
+//! to hear how it sounds across locales
+
+use futures_util::future::join_all;
+
+use crate::{
+    ElevenLabsTTVError, LanguageCode, TTVDesignVoiceResponse, TextToVoiceDesignVoiceBuilder,
+};
+
+/// Preview texts for `design_per_language`, each within the API's 100 to 1000 characters
+const SAMPLE_TEXTS: [(LanguageCode, &str); 11] = [
+    (
+        LanguageCode::En,
+        "Hello! This is a short preview of my voice. I enjoy telling stories, reading the news \
+         and explaining new ideas clearly. Thank you for listening, and I hope you like how I \
+         sound.",
+    ),
+    (
+        LanguageCode::Es,
+        "¡Hola! Esta es una breve muestra de mi voz. Me encanta contar historias, leer las \
+         noticias y explicar ideas nuevas con claridad. Gracias por escuchar, y espero que te \
+         guste cómo sueno.",
+    ),
+    (
+        LanguageCode::Fr,
+        "Bonjour ! Voici un court aperçu de ma voix. J'aime raconter des histoires, lire les \
+         nouvelles et expliquer clairement de nouvelles idées. Merci de votre écoute, et \
+         j'espère que ma voix vous plaît.",
+    ),
+    (
+        LanguageCode::De,
+        "Hallo! Dies ist eine kurze Hörprobe meiner Stimme. Ich erzähle gerne Geschichten, lese \
+         Nachrichten vor und erkläre neue Ideen verständlich. Danke fürs Zuhören, ich hoffe, \
+         meine Stimme gefällt Ihnen.",
+    ),
+    (
+        LanguageCode::It,
+        "Ciao! Questo è un breve assaggio della mia voce. Mi piace raccontare storie, leggere le \
+         notizie e spiegare nuove idee con chiarezza. Grazie per l'ascolto, e spero che la mia \
+         voce ti piaccia.",
+    ),
+    (
+        LanguageCode::Pt,
+        "Olá! Esta é uma breve amostra da minha voz. Eu gosto de contar histórias, ler as \
+         notícias e explicar novas ideias com clareza. Obrigado por ouvir, e espero que goste \
+         da minha voz.",
+    ),
+    (
+        LanguageCode::Nl,
+        "Hallo! Dit is een korte voorproef van mijn stem. Ik vertel graag verhalen, lees het \
+         nieuws voor en leg nieuwe ideeën helder uit. Bedankt voor het luisteren, en ik hoop dat \
+         je mijn stem mooi vindt.",
+    ),
+    (
+        LanguageCode::Pl,
+        "Cześć! To jest krótka próbka mojego głosu. Lubię opowiadać historie, czytać wiadomości \
+         i jasno wyjaśniać nowe pomysły. Dziękuję za wysłuchanie i mam nadzieję, że mój głos ci \
+         się podoba.",
+    ),
+    (
+        LanguageCode::Ja,
+        "こんにちは！これは私の声の短いサンプルです。物語を語ったり、ニュースを読んだり、\
+         新しいアイデアを分かりやすく説明したりするのが大好きです。最後まで聞いていただき、\
+         ありがとうございます。私の声を気に入っていただけたら、とてもうれしいです。",
+    ),
+    (
+        LanguageCode::Zh,
+        "你好！这是我声音的一段简短试听。我喜欢讲故事、播报新闻，也喜欢把新的想法解释得清楚明白。\
+         感谢你的收听，希望你会喜欢我的声音。如果你愿意，我们可以一起开始一段新的故事，\
+         让每一句话都更加生动有趣，也让每一个想法都更容易被理解。",
+    ),
+    (
+        LanguageCode::Hi,
+        "नमस्ते! यह मेरी आवाज़ का एक छोटा सा नमूना है। मुझे कहानियाँ सुनाना, समाचार पढ़ना और \
+         नए विचारों को स्पष्ट रूप से समझाना पसंद है। सुनने के लिए धन्यवाद, मुझे उम्मीद है कि \
+         आपको मेरी आवाज़ पसंद आएगी।",
+    ),
+];
+
+/// The bundled preview text for `language`, if there is one
+pub fn sample_text(language: &LanguageCode) -> Option<&'static str> {
+    SAMPLE_TEXTS
+        .iter()
+        .find(|(known, _)| known == language)
+        .map(|(_, text)| *text)
+}
+
+/// Outcome of one design of `design_per_language`
+#[derive(Debug)]
+pub struct LanguageDesignResult {
+    pub language: LanguageCode,
+    /// The preview text used, from `sample_text`
+    pub text: &'static str,
+    pub result: Result<TTVDesignVoiceResponse, ElevenLabsTTVError>,
+}
+
+impl<'a> TextToVoiceDesignVoiceBuilder<'a> {
+    /// Run the design once per language, concurrently, each with the language's `sample_text`
+    /// as preview text (replacing any `text` set on the builder). Results are labeled and in
+    /// the order of `languages`; a failed design does not fail the others.
+    ///
+    /// Use a multilingual model (the default) and a `.seed(...)` so previews differ only by
+    /// their language. Fails without sending anything if the builder is invalid or a language
+    /// has no sample text.
+    pub async fn design_per_language<I, L>(
+        self,
+        languages: I,
+    ) -> Result<Vec<LanguageDesignResult>, ElevenLabsTTVError>
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<LanguageCode>,
+    {
+        let (client, request) = self.into_parts()?;
+
+        let requests = languages
+            .into_iter()
+            .map(|language| {
+                let language = language.into();
+                let text = sample_text(&language).ok_or_else(|| {
+                    ElevenLabsTTVError::ValidationError(format!(
+                        "no sample text for language {}",
+                        language
+                    ))
+                })?;
+                let mut request = request.clone();
+                request.text = Some(text.to_string());
+                request.auto_generate_text = Some(false);
+                Ok((language, text, request))
+            })
+            .collect::<Result<Vec<_>, ElevenLabsTTVError>>()?;
+
+        Ok(join_all(
+            requests
+                .into_iter()
+                .map(|(language, text, request)| async move {
+                    LanguageDesignResult {
+                        language,
+                        text,
+                        result: client.execute_design_voice(request).await,
+                    }
+                }),
+        )
+        .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElevenLabsTTVClient;
+
+    #[test]
+    fn test_sample_texts_are_valid_preview_texts() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        for (language, text) in SAMPLE_TEXTS {
+            assert!(
+                client
+                    .design_voice("Calm, deep-voiced narrator")
+                    .text(text)
+                    .build()
+                    .is_ok(),
+                "{}",
+                language
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_design_per_language_rejects_unknown_languages() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let result = client
+            .design_voice("Calm, deep-voiced narrator")
+            .design_per_language(["de", "xx"])
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ElevenLabsTTVError::ValidationError(msg)) if msg.contains("xx")
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! OpenTelemetry client spans for API calls (`otel` feature)
+//!
+//! One span per HTTP attempt is started with the globally registered tracer provider
+//! (`opentelemetry::global::set_tracer_provider`), as a child of the current context,
+//! with the HTTP semantic convention attributes. Without the feature these are no-ops.
+
+use reqwest::Request;
+
+use crate::ElevenLabsTTVError;
+
+#[cfg(feature = "otel")]
+pub(crate) struct ApiSpan(opentelemetry::global::BoxedSpan);
+
+#[cfg(feature = "otel")]
+impl ApiSpan {
+    pub(crate) fn start(endpoint: &'static str, request: &Request, attempt: usize) -> Self {
+        use opentelemetry::KeyValue;
+        use opentelemetry::trace::{SpanKind, Tracer};
+
+        let url = request.url();
+        let mut attributes = vec![
+            KeyValue::new("http.request.method", request.method().as_str().to_string()),
+            KeyValue::new("url.full", url.to_string()),
+            KeyValue::new("url.template", endpoint),
+            KeyValue::new(
+                "server.address",
+                url.host_str().unwrap_or_default().to_string(),
+            ),
+        ];
+        if let Some(port) = url.port_or_known_default() {
+            attributes.push(KeyValue::new("server.port", i64::from(port)));
+        }
+        if attempt > 1 {
+            attributes.push(KeyValue::new(
+                "http.request.resend_count",
+                (attempt - 1) as i64,
+            ));
+        }
+
+        let tracer = opentelemetry::global::tracer("elevenlabs_ttv");
+        let span = tracer
+            .span_builder(format!("{} {}", request.method(), endpoint))
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        Self(span)
+    }
+
+    pub(crate) fn record_status(&mut self, status: u16) {
+        use opentelemetry::KeyValue;
+        use opentelemetry::trace::{Span, Status};
+
+        self.0.set_attribute(KeyValue::new(
+            "http.response.status_code",
+            i64::from(status),
+        ));
+        if status >= 400 {
+            self.0
+                .set_attribute(KeyValue::new("error.type", status.to_string()));
+            self.0.set_status(Status::error(format!("HTTP {}", status)));
+        }
+    }
+
+    pub(crate) fn record_error(&mut self, error: &ElevenLabsTTVError) {
+        use opentelemetry::KeyValue;
+        use opentelemetry::trace::{Span, Status};
+
+        let error_type = match error {
+            ElevenLabsTTVError::RequestError { source: e, .. } if e.is_timeout() => "timeout",
+            ElevenLabsTTVError::RequestError { source: e, .. } if e.is_connect() => "connect",
+            ElevenLabsTTVError::MiddlewareError(_) => "middleware",
+            _ => "request",
+        };
+        self.0
+            .set_attribute(KeyValue::new("error.type", error_type));
+        self.0.set_status(Status::error(error.to_string()));
+    }
+
+    /// End the span (also done when it is dropped)
+    pub(crate) fn end(self) {}
+}
+
+#[cfg(feature = "otel")]
+impl Drop for ApiSpan {
+    fn drop(&mut self) {
+        use opentelemetry::trace::Span;
+
+        self.0.end();
+    }
+}
+
+#[cfg(not(feature = "otel"))]
+pub(crate) struct ApiSpan;
+
+#[cfg(not(feature = "otel"))]
+impl ApiSpan {
+    pub(crate) fn start(_endpoint: &'static str, _request: &Request, _attempt: usize) -> Self {
+        Self
+    }
+
+    pub(crate) fn record_status(&mut self, _status: u16) {}
+
+    pub(crate) fn record_error(&mut self, _error: &ElevenLabsTTVError) {}
+
+    pub(crate) fn end(self) {}
+}
@@ -0,0 +1,236 @@
+use std::time::Duration;
+
+use crate::{ElevenLabsTTVError, TTVDesignVoiceResponseVoicePreview};
+
+/// Uncompressed 16-bit audio, with samples interleaved by channel
+///
+/// Previews requested with a `pcm_*` output format are raw 16-bit little-endian mono PCM,
+/// see `PcmAudio::from_s16le`. With the `encode` feature, `PcmAudio` can be transcoded
+/// to FLAC or OGG Vorbis; with the `audio` feature, it can be read from and written to WAV,
+/// downmixed and loudness-normalized. With the `decode` feature, MP3 previews can be decoded
+/// to `PcmAudio` with `preview.decode_pcm()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcmAudio {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub samples: Vec<i16>,
+}
+
+impl PcmAudio {
+    /// Read raw 16-bit little-endian PCM, e.g. decoded `pcm_24000` preview audio
+    pub fn from_s16le(
+        bytes: &[u8],
+        sample_rate: u32,
+        channels: u16,
+    ) -> Result<Self, ElevenLabsTTVError> {
+        if channels == 0 || sample_rate == 0 {
+            return Err(ElevenLabsTTVError::ValidationError(
+                "sample_rate and channels must be greater than 0".to_string(),
+            ));
+        }
+        if !bytes.len().is_multiple_of(2 * channels as usize) {
+            return Err(ElevenLabsTTVError::AudioDecodeError(format!(
+                "{} bytes is not a whole number of 16-bit frames with {} channel(s)",
+                bytes.len(),
+                channels
+            )));
+        }
+
+        Ok(Self {
+            sample_rate,
+            channels,
+            samples: bytes
+                .chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+                .collect(),
+        })
+    }
+
+    /// Number of samples per channel
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels as usize
+    }
+
+    /// Duration of the audio in seconds
+    pub fn duration_secs(&self) -> f64 {
+        self.frames() as f64 / self.sample_rate as f64
+    }
+
+    /// Duration of the audio
+    pub fn duration(&self) -> Duration {
+        crate::types::secs_to_duration(self.duration_secs())
+    }
+
+    /// Cut the audio to at most `max_duration`, fading the last `fade_out` of what is kept
+    /// to silence so the cut doesn't click. Shorter audio is returned as-is.
+    pub fn trim(&self, max_duration: Duration, fade_out: Duration) -> Self {
+        let channels = self.channels as usize;
+        let max_frames = (max_duration.as_secs_f64() * self.sample_rate as f64) as usize;
+        if self.frames() <= max_frames {
+            return self.clone();
+        }
+
+        let mut samples = self.samples[..max_frames * channels].to_vec();
+        let fade_frames =
+            ((fade_out.as_secs_f64() * self.sample_rate as f64) as usize).min(max_frames);
+        let fade_start = max_frames - fade_frames;
+        for frame in fade_start..max_frames {
+            let gain = (max_frames - frame - 1) as f64 / fade_frames as f64;
+            for sample in &mut samples[frame * channels..(frame + 1) * channels] {
+                *sample = (f64::from(*sample) * gain).round() as i16;
+            }
+        }
+
+        Self {
+            sample_rate: self.sample_rate,
+            channels: self.channels,
+            samples,
+        }
+    }
+
+    /// Convert to another sample rate, e.g. `pcm_44100` previews to 16 kHz for telephony
+    /// or ASR pipelines
+    ///
+    /// Uses windowed-sinc interpolation, low-pass filtering when downsampling so
+    /// frequencies above the new Nyquist rate don't alias.
+    pub fn resample(&self, sample_rate: u32) -> Self {
+        if sample_rate == self.sample_rate || sample_rate == 0 || self.samples.is_empty() {
+            return self.clone();
+        }
+
+        let channels = self.channels as usize;
+        let frames = self.frames();
+        let ratio = self.sample_rate as f64 / sample_rate as f64;
+        // Cutoff as a fraction of the input Nyquist rate
+        let cutoff = (1.0 / ratio).min(1.0);
+        let half_width = RESAMPLE_ZERO_CROSSINGS / cutoff;
+        let output_frames = (frames as f64 / ratio).round() as usize;
+        let mut samples = Vec::with_capacity(output_frames * channels);
+
+        for frame in 0..output_frames {
+            let center = frame as f64 * ratio;
+            let first = (center - half_width).ceil().max(0.0) as usize;
+            let last = ((center + half_width).floor() as usize).min(frames - 1);
+
+            let mut sums = vec![0.0; channels];
+            let mut total_weight = 0.0;
+            for input in first..=last {
+                let distance = input as f64 - center;
+                let window = 0.5 + 0.5 * (std::f64::consts::PI * distance / half_width).cos();
+                let weight = sinc(cutoff * distance) * window;
+                total_weight += weight;
+                for (channel, sum) in sums.iter_mut().enumerate() {
+                    *sum += weight * f64::from(self.samples[input * channels + channel]);
+                }
+            }
+            samples.extend(
+                sums.into_iter()
+                    .map(|sum| (sum / total_weight).round().clamp(-32768.0, 32767.0) as i16),
+            );
+        }
+
+        Self {
+            sample_rate,
+            channels: self.channels,
+            samples,
+        }
+    }
+}
+
+impl TTVDesignVoiceResponseVoicePreview {
+    /// Decode the preview audio of a design requested with a `pcm_*` output format,
+    /// e.g. `preview.pcm_audio("pcm_24000")?.to_flac()?` with the `encode` feature
+    pub fn pcm_audio(&self, output_format: &str) -> Result<PcmAudio, ElevenLabsTTVError> {
+        let sample_rate = pcm_sample_rate(output_format).ok_or_else(|| {
+            ElevenLabsTTVError::ValidationError(format!(
+                "{} is not a pcm_* output format",
+                output_format
+            ))
+        })?;
+
+        PcmAudio::from_s16le(&self.decode_audio()?, sample_rate, 1)
+    }
+}
+
+/// Zero crossings of the sinc kernel on each side, at the output rate
+const RESAMPLE_ZERO_CROSSINGS: f64 = 8.0;
+
+fn sinc(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let x = std::f64::consts::PI * x;
+        x.sin() / x
+    }
+}
+
+/// Sample rate of a `pcm_*` output format, e.g. 24000 for "pcm_24000"
+pub fn pcm_sample_rate(output_format: &str) -> Option<u32> {
+    output_format.strip_prefix("pcm_")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_s16le() {
+        let audio = PcmAudio::from_s16le(&[0x01, 0x00, 0xff, 0xff], 24000, 1).unwrap();
+        assert_eq!(audio.samples, vec![1, -1]);
+        assert_eq!(audio.frames(), 2);
+        assert!(PcmAudio::from_s16le(&[0x01, 0x00, 0xff], 24000, 1).is_err());
+        assert_eq!(pcm_sample_rate("pcm_24000"), Some(24000));
+        assert_eq!(pcm_sample_rate("mp3_44100_128"), None);
+    }
+
+    #[test]
+    fn test_trim_fades_out() {
+        let audio = PcmAudio {
+            sample_rate: 10,
+            channels: 2,
+            samples: vec![1000; 100],
+        };
+
+        let trimmed = audio.trim(Duration::from_secs(3), Duration::from_millis(500));
+        assert_eq!(trimmed.frames(), 30);
+        assert_eq!(trimmed.samples[48..50], [1000, 1000]);
+        assert_eq!(trimmed.samples[50..52], [800, 800]);
+        assert_eq!(trimmed.samples[58..], [0, 0]);
+        assert_eq!(audio.trim(Duration::from_secs(60), Duration::ZERO), audio);
+    }
+
+    #[test]
+    fn test_resample() {
+        let tone = |frequency: f64, sample_rate: u32| PcmAudio {
+            sample_rate,
+            channels: 1,
+            samples: (0..sample_rate)
+                .map(|i| {
+                    let t = i as f64 / sample_rate as f64;
+                    (10000.0 * (frequency * t * std::f64::consts::TAU).sin()) as i16
+                })
+                .collect(),
+        };
+        let peak = |audio: &PcmAudio| {
+            // Skip the edges, where the kernel is truncated
+            let frames = audio.frames();
+            audio.samples[frames / 4..frames * 3 / 4]
+                .iter()
+                .map(|s| s.unsigned_abs())
+                .max()
+                .unwrap()
+        };
+
+        let speech = tone(440.0, 44100).resample(16000);
+        assert_eq!(speech.sample_rate, 16000);
+        assert_eq!(speech.frames(), 16000);
+        assert!((9500..=10500).contains(&peak(&speech)));
+
+        // Above the 8 kHz Nyquist rate of the output, so filtered out instead of aliased
+        let hiss = tone(12000.0, 44100).resample(16000);
+        assert!(peak(&hiss) < 500);
+
+        let upsampled = tone(440.0, 16000).resample(48000);
+        assert_eq!(upsampled.frames(), 48000);
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// Sensible combinations of `quality`, `guidance_scale` and `loudness` for Design Voice,
+/// applied with `TextToVoiceDesignVoiceBuilder::preset`
+///
+/// Setters called after `.preset(...)` override the preset's value for that parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DesignPreset {
+    /// Lots of variety, loose prompt adherence. Good for exploring ideas quickly.
+    Draft,
+    /// The API defaults
+    Balanced,
+    /// Cleaner output that sticks closely to the description, with less variety.
+    /// Works best with long, detailed descriptions.
+    HighQuality,
+}
+
+impl DesignPreset {
+    /// Value for `quality` (-1 to 1)
+    pub fn quality(self) -> f32 {
+        match self {
+            DesignPreset::Draft => -0.5,
+            DesignPreset::Balanced => 0.0,
+            DesignPreset::HighQuality => 0.8,
+        }
+    }
+
+    /// Value for `guidance_scale` (0 to 100)
+    pub fn guidance_scale(self) -> u32 {
+        match self {
+            DesignPreset::Draft => 3,
+            DesignPreset::Balanced => 5,
+            DesignPreset::HighQuality => 10,
+        }
+    }
+
+    /// Value for `loudness` (-1 to 1)
+    pub fn loudness(self) -> f32 {
+        0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElevenLabsTTVClient;
+
+    #[test]
+    fn test_preset_applies_and_can_be_overridden() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let request = client
+            .design_voice("Gravelly old sea captain")
+            .preset(DesignPreset::HighQuality)
+            .guidance_scale(8)
+            .build()
+            .unwrap();
+
+        assert_eq!(request.quality, Some(0.8));
+        assert_eq!(request.guidance_scale, Some(8));
+        assert_eq!(request.loudness, Some(0.5));
+    }
+}
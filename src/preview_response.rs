@@ -0,0 +1,274 @@
+//! Serving preview audio from web handlers (`axum` and `actix` features)
+//!
+//! `PreviewResponse` carries preview audio with the headers a browser needs to play and
+//! cache it, e.g. for internal voice-audition tools:
+//! `async fn preview(...) -> Result<PreviewResponse, _> { client.preview_response(&id).await }`.
+//! It is an axum `IntoResponse` and an actix-web `Responder`, as are `DecodedVoicePreview`
+//! and, for actix-web, `TTVDesignVoiceResponse` (as JSON).
+
+use bytes::Bytes;
+use http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE, ETAG};
+use http::{HeaderName, HeaderValue};
+
+use crate::{DecodedVoicePreview, ElevenLabsTTVClient, ElevenLabsTTVError, MediaType};
+
+/// Preview audio never changes for a generated voice ID, so browsers may keep it for the
+/// day previews stay usable
+const PREVIEW_CACHE_CONTROL: &str = "private, max-age=86400, immutable";
+
+/// Preview audio ready to be sent to a browser, see the module docs
+///
+/// Sets `Content-Type` from the preview's media type, `Content-Length` when known, an
+/// `ETag` and `Content-Disposition` from the generated voice ID, and a private,
+/// immutable `Cache-Control`.
+#[derive(Debug)]
+pub struct PreviewResponse {
+    generated_voice_id: String,
+    media_type: MediaType,
+    body: PreviewBody,
+}
+
+#[derive(Debug)]
+enum PreviewBody {
+    Full(Bytes),
+    /// A preview-stream response, forwarded chunk by chunk as it is received
+    Streamed(reqwest::Response),
+}
+
+impl PreviewResponse {
+    /// Preview audio already in memory, e.g. from `stream_preview`
+    pub fn new(
+        generated_voice_id: impl Into<String>,
+        media_type: impl Into<MediaType>,
+        audio: impl Into<Bytes>,
+    ) -> Self {
+        Self {
+            generated_voice_id: generated_voice_id.into(),
+            media_type: media_type.into(),
+            body: PreviewBody::Full(audio.into()),
+        }
+    }
+
+    pub fn generated_voice_id(&self) -> &str {
+        &self.generated_voice_id
+    }
+
+    pub fn media_type(&self) -> &MediaType {
+        &self.media_type
+    }
+
+    fn content_length(&self) -> Option<u64> {
+        match &self.body {
+            PreviewBody::Full(audio) => Some(audio.len() as u64),
+            PreviewBody::Streamed(upstream) => upstream.content_length(),
+        }
+    }
+
+    fn headers(&self) -> Vec<(HeaderName, HeaderValue)> {
+        let mut headers = Vec::new();
+        if let Ok(media_type) = HeaderValue::from_str(self.media_type.as_str()) {
+            headers.push((CONTENT_TYPE, media_type));
+        }
+        if let Some(content_length) = self.content_length() {
+            headers.push((CONTENT_LENGTH, content_length.into()));
+        }
+        headers.push((
+            CACHE_CONTROL,
+            HeaderValue::from_static(PREVIEW_CACHE_CONTROL),
+        ));
+        // Generated voice IDs are alphanumeric; others are left without these headers
+        if let Ok(etag) = HeaderValue::from_str(&format!("\"{}\"", self.generated_voice_id)) {
+            headers.push((ETAG, etag));
+        }
+        if let Ok(disposition) = HeaderValue::from_str(&format!(
+            "inline; filename=\"{}.{}\"",
+            self.generated_voice_id,
+            self.media_type.file_extension()
+        )) {
+            headers.push((CONTENT_DISPOSITION, disposition));
+        }
+        headers
+    }
+}
+
+impl From<DecodedVoicePreview> for PreviewResponse {
+    fn from(preview: DecodedVoicePreview) -> Self {
+        Self::new(
+            preview.generated_voice_id,
+            preview.media_type,
+            preview.audio,
+        )
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// Start downloading a preview generated with `stream_previews(true)`, to forward its
+    /// audio to a browser as it is received (see `stream_preview`)
+    ///
+    /// Fails if the API rejects the request; once the response has started, a download
+    /// error ends the forwarded body early.
+    pub async fn preview_response(
+        &self,
+        generated_voice_id: &str,
+    ) -> Result<PreviewResponse, ElevenLabsTTVError> {
+        let url = self.url(&["text-to-voice", generated_voice_id, "stream"])?;
+        let response = self
+            .send(
+                "text-to-voice/{generated_voice_id}/stream",
+                self.inner.client.get(url),
+            )
+            .await?;
+
+        let media_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|media_type| media_type.to_str().ok())
+            .map_or(MediaType::AudioMpeg, MediaType::from);
+
+        Ok(PreviewResponse {
+            generated_voice_id: generated_voice_id.to_string(),
+            media_type,
+            body: PreviewBody::Streamed(response),
+        })
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_impls {
+    use axum::body::Body;
+    use axum::response::{IntoResponse, Response};
+
+    use super::{PreviewBody, PreviewResponse};
+    use crate::DecodedVoicePreview;
+
+    impl IntoResponse for PreviewResponse {
+        fn into_response(self) -> Response {
+            let headers = self.headers();
+            let body = match self.body {
+                PreviewBody::Full(audio) => Body::from(audio),
+                PreviewBody::Streamed(upstream) => Body::from_stream(upstream.bytes_stream()),
+            };
+
+            let mut response = body.into_response();
+            for (name, value) in headers {
+                response.headers_mut().insert(name, value);
+            }
+            response
+        }
+    }
+
+    impl IntoResponse for DecodedVoicePreview {
+        fn into_response(self) -> Response {
+            PreviewResponse::from(self).into_response()
+        }
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix_impls {
+    use actix_web::body::BoxBody;
+    use actix_web::http::header::HeaderValue;
+    use actix_web::{HttpRequest, HttpResponse, Responder};
+    use http::header::CONTENT_LENGTH;
+
+    use super::{PreviewBody, PreviewResponse};
+    use crate::{DecodedVoicePreview, TTVDesignVoiceResponse};
+
+    impl Responder for PreviewResponse {
+        type Body = BoxBody;
+
+        fn respond_to(self, _: &HttpRequest) -> HttpResponse {
+            let mut response = HttpResponse::Ok();
+            // actix-web uses an older `http`, so the headers are copied over by value. It
+            // sets `Content-Length` itself, from the body or `no_chunking`
+            for (name, value) in self.headers() {
+                if name != CONTENT_LENGTH
+                    && let Ok(value) = HeaderValue::from_bytes(value.as_bytes())
+                {
+                    response.insert_header((name.as_str(), value));
+                }
+            }
+            match self.body {
+                PreviewBody::Full(audio) => response.body(audio),
+                PreviewBody::Streamed(upstream) => {
+                    if let Some(content_length) = upstream.content_length() {
+                        response.no_chunking(content_length);
+                    }
+                    response.streaming(upstream.bytes_stream())
+                }
+            }
+        }
+    }
+
+    impl Responder for DecodedVoicePreview {
+        type Body = BoxBody;
+
+        fn respond_to(self, request: &HttpRequest) -> HttpResponse {
+            PreviewResponse::from(self).respond_to(request)
+        }
+    }
+
+    impl Responder for TTVDesignVoiceResponse {
+        type Body = BoxBody;
+
+        fn respond_to(self, _: &HttpRequest) -> HttpResponse {
+            HttpResponse::Ok().json(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_preview_response_headers() {
+        use axum::response::IntoResponse;
+
+        let response = PreviewResponse::new("gen1", "audio/mpeg", Bytes::from_static(b"hello"))
+            .into_response();
+
+        let headers = response.headers();
+        assert_eq!(headers[CONTENT_TYPE], "audio/mpeg");
+        assert_eq!(headers[CONTENT_LENGTH], "5");
+        assert_eq!(headers[CACHE_CONTROL], PREVIEW_CACHE_CONTROL);
+        assert_eq!(headers[ETAG], "\"gen1\"");
+        assert_eq!(
+            headers[CONTENT_DISPOSITION],
+            "inline; filename=\"gen1.mp3\""
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, "hello");
+    }
+
+    #[cfg(feature = "actix")]
+    #[tokio::test]
+    async fn test_preview_response_actix_responder() {
+        use actix_web::Responder;
+        use actix_web::http::header;
+
+        let request = actix_web::test::TestRequest::default().to_http_request();
+        let response = PreviewResponse::new("gen1", "audio/wav", Bytes::from_static(b"hello"))
+            .respond_to(&request);
+
+        let headers = response.headers();
+        assert_eq!(headers.get(header::CONTENT_TYPE).unwrap(), "audio/wav");
+        assert_eq!(
+            headers.get(header::CACHE_CONTROL).unwrap(),
+            PREVIEW_CACHE_CONTROL
+        );
+        assert_eq!(
+            headers.get(header::CONTENT_DISPOSITION).unwrap(),
+            "inline; filename=\"gen1.wav\""
+        );
+
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        assert_eq!(body, "hello");
+    }
+}
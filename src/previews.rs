@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use bytes::{Bytes, BytesMut};
+use futures_util::{StreamExt, stream};
+
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, ProgressReporter, TTVDesignVoiceResponse};
+
+impl ElevenLabsTTVClient {
+    /// Download the audio of a preview generated with `stream_previews(true)`
+    ///
+    /// Wraps GET /v1/text-to-voice/:generated_voice_id/stream and returns the raw audio bytes.
+    pub async fn stream_preview(
+        &self,
+        generated_voice_id: &str,
+    ) -> Result<Bytes, ElevenLabsTTVError> {
+        self.download_preview(generated_voice_id, None).await
+    }
+
+    /// `stream_preview`, reporting each received chunk to `progress`
+    async fn download_preview(
+        &self,
+        generated_voice_id: &str,
+        progress: Option<&dyn ProgressReporter>,
+    ) -> Result<Bytes, ElevenLabsTTVError> {
+        let endpoint = "text-to-voice/{generated_voice_id}/stream";
+        let url = self.url(&["text-to-voice", generated_voice_id, "stream"])?;
+
+        let mut response = self.send(endpoint, self.inner.client.get(url)).await?;
+        let audio = match progress {
+            None => response.bytes().await?,
+            Some(progress) => {
+                progress.start(generated_voice_id, response.content_length());
+                let mut audio = BytesMut::new();
+                let result = async {
+                    while let Some(chunk) = response.chunk().await? {
+                        progress.advance(generated_voice_id, chunk.len() as u64);
+                        audio.extend_from_slice(&chunk);
+                    }
+                    Ok::<_, ElevenLabsTTVError>(())
+                }
+                .await;
+                progress.finish(generated_voice_id);
+                result?;
+                audio.freeze()
+            }
+        };
+        self.record_audio_bytes(endpoint, audio.len() as u64);
+
+        Ok(audio)
+    }
+}
+
+impl TTVDesignVoiceResponse {
+    /// Get the audio of every preview, in preview order
+    ///
+    /// Previews generated with `stream_previews(true)` only carry their ID and are downloaded
+    /// concurrently via `stream_preview`; previews with inline audio are decoded locally.
+    pub fn fetch_all_previews<'a>(
+        &'a self,
+        client: &'a ElevenLabsTTVClient,
+    ) -> FetchPreviewsBuilder<'a> {
+        FetchPreviewsBuilder {
+            client,
+            response: self,
+            concurrency: 3,
+            progress: None,
+        }
+    }
+}
+
+/// Builder for fetching the audio of all previews, see `TTVDesignVoiceResponse::fetch_all_previews`
+pub struct FetchPreviewsBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
+    response: &'a TTVDesignVoiceResponse,
+    pub concurrency: usize,
+    pub progress: Option<Arc<dyn ProgressReporter>>,
+}
+
+impl FetchPreviewsBuilder<'_> {
+    /// Maximum number of previews downloaded at the same time. Defaults to 3.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Report per-preview progress, keyed by generated voice ID.
+    /// With the `progress` feature, pass an `IndicatifProgress` to show progress bars.
+    pub fn progress(mut self, progress: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Fetch the previews. Fails on the first preview that cannot be fetched or decoded.
+    pub async fn execute(self) -> Result<Vec<Bytes>, ElevenLabsTTVError> {
+        let previews = &self.response.previews;
+        let mut audio: Vec<Option<Bytes>> = vec![None; previews.len()];
+        let mut pending = Vec::new();
+
+        for (index, preview) in previews.iter().enumerate() {
+            if preview.audio_base_64.is_empty() {
+                pending.push((index, preview.generated_voice_id.clone()));
+            } else {
+                let decoded = preview.decode_audio()?;
+                if let Some(progress) = &self.progress {
+                    let key = &preview.generated_voice_id;
+                    progress.start(key, Some(decoded.len() as u64));
+                    progress.advance(key, decoded.len() as u64);
+                    progress.finish(key);
+                }
+                audio[index] = Some(decoded);
+            }
+        }
+
+        let mut downloads = stream::iter(pending)
+            .map(|(index, generated_voice_id)| {
+                let progress = self.progress.clone();
+                async move {
+                    let audio = self
+                        .client
+                        .download_preview(&generated_voice_id, progress.as_deref())
+                        .await;
+                    (index, audio)
+                }
+            })
+            // `concurrency` is public, so it may bypass the clamp of the setter
+            .buffer_unordered(self.concurrency.max(1));
+        while let Some((index, bytes)) = downloads.next().await {
+            audio[index] = Some(bytes?);
+        }
+
+        Ok(audio.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TTVDesignVoiceResponseVoicePreview;
+
+    #[tokio::test]
+    async fn test_fetch_all_previews_decodes_inline_audio() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture().audio_base_64("aGVsbG8="),
+        ]);
+
+        let audio = response
+            .fetch_all_previews(&client)
+            .concurrency(2)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(audio, vec![Bytes::from_static(b"hello")]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_previews_with_zero_concurrency() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .mount(&server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-1")
+                .audio_base_64(""),
+        ]);
+
+        let mut builder = response.fetch_all_previews(&client);
+        builder.concurrency = 0;
+        let audio = tokio::time::timeout(std::time::Duration::from_secs(5), builder.execute())
+            .await
+            .expect("execute hung")
+            .unwrap();
+        assert_eq!(audio, vec![Bytes::from_static(b"hello")]);
+    }
+}
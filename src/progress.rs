@@ -0,0 +1,153 @@
+//! Progress reporting for long-running helpers such as `fetch_all_previews`
+//!
+//! Implement `ProgressReporter` to forward progress to your UI. With the `progress` feature,
+//! `IndicatifProgress` renders one `indicatif` progress bar per item in a `MultiProgress`.
+
+/// Receives progress updates for each item (e.g. preview or voice) processed by a helper
+///
+/// Items are identified by a key such as the generated voice ID. Calls for different items
+/// can be interleaved when the helper works concurrently.
+pub trait ProgressReporter: Send + Sync {
+    /// Work on an item started. `total` is its size in bytes, if known.
+    fn start(&self, key: &str, total: Option<u64>) {
+        let _ = (key, total);
+    }
+
+    /// `delta` more bytes of the item were processed
+    fn advance(&self, key: &str, delta: u64) {
+        let _ = (key, delta);
+    }
+
+    /// Work on the item finished, successfully or not
+    fn finish(&self, key: &str) {
+        let _ = key;
+    }
+}
+
+#[cfg(feature = "progress")]
+pub use self::indicatif_progress::IndicatifProgress;
+
+#[cfg(feature = "progress")]
+mod indicatif_progress {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+
+    use super::ProgressReporter;
+
+    /// `ProgressReporter` showing one `indicatif` progress bar per item
+    pub struct IndicatifProgress {
+        multi: MultiProgress,
+        bars: Mutex<HashMap<String, ProgressBar>>,
+    }
+
+    impl IndicatifProgress {
+        /// Draw bars into the given `MultiProgress`, e.g. to share it with your own bars
+        pub fn new(multi: MultiProgress) -> Self {
+            Self {
+                multi,
+                bars: Mutex::new(HashMap::new()),
+            }
+        }
+
+        /// The underlying `MultiProgress`
+        pub fn multi_progress(&self) -> &MultiProgress {
+            &self.multi
+        }
+    }
+
+    impl Default for IndicatifProgress {
+        fn default() -> Self {
+            Self::new(MultiProgress::new())
+        }
+    }
+
+    impl ProgressReporter for IndicatifProgress {
+        fn start(&self, key: &str, total: Option<u64>) {
+            let bar = match total {
+                Some(total) => ProgressBar::new(total).with_style(
+                    ProgressStyle::with_template("{prefix} [{bar:30}] {bytes}/{total_bytes}")
+                        .unwrap_or_else(|_| ProgressStyle::default_bar()),
+                ),
+                None => ProgressBar::new_spinner().with_style(
+                    ProgressStyle::with_template("{prefix} {spinner} {bytes}")
+                        .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+                ),
+            };
+            let bar = self.multi.add(bar.with_prefix(key.to_string()));
+            self.bars.lock().unwrap().insert(key.to_string(), bar);
+        }
+
+        fn advance(&self, key: &str, delta: u64) {
+            if let Some(bar) = self.bars.lock().unwrap().get(key) {
+                bar.inc(delta);
+            }
+        }
+
+        fn finish(&self, key: &str) {
+            if let Some(bar) = self.bars.lock().unwrap().remove(key) {
+                bar.finish();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl ProgressReporter for Recorder {
+        fn start(&self, key: &str, total: Option<u64>) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("start {} {:?}", key, total));
+        }
+
+        fn advance(&self, key: &str, delta: u64) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("advance {} {}", key, delta));
+        }
+
+        fn finish(&self, key: &str) {
+            self.0.lock().unwrap().push(format!("finish {}", key));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_previews_reports_progress() {
+        use std::sync::Arc;
+
+        use crate::{
+            ElevenLabsTTVClient, TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview,
+        };
+
+        let client = ElevenLabsTTVClient::new("test-key");
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-1")
+                .audio_base_64("aGVsbG8="),
+        ]);
+
+        let recorder = Arc::new(Recorder::default());
+        response
+            .fetch_all_previews(&client)
+            .progress(recorder.clone())
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            ["start gen-1 Some(5)", "advance gen-1 5", "finish gen-1"]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Voice description templates for common archetypes
+//!
+//! Each template returns a `VoiceDescription`, so any part can be overridden or extended
+//! before designing: `client.design_voice(prompts::villain(Gender::Male).accent("Russian"))`.
+
+use crate::{Gender, Pace, VoiceDescription};
+
+/// Audiobook or documentary narrator: calm, clear and measured
+pub fn narrator(gender: Gender) -> VoiceDescription {
+    VoiceDescription::new()
+        .gender(gender)
+        .age_range(40..55)
+        .tone("calm, warm and authoritative")
+        .pace(Pace::Moderate)
+        .detail("Clear diction and a rich, resonant timbre, suited to long-form narration")
+        .detail("Studio-quality recording")
+}
+
+/// Customer support agent: friendly, patient and easy to understand
+pub fn customer_support_agent(gender: Gender) -> VoiceDescription {
+    VoiceDescription::new()
+        .gender(gender)
+        .age_range(25..35)
+        .tone("friendly, patient and reassuring")
+        .pace(Pace::Moderate)
+        .detail("Professional and upbeat without sounding scripted")
+        .detail("Clean recording with no background noise")
+}
+
+/// Theatrical villain: menacing, deliberate and slightly gravelly
+pub fn villain(gender: Gender) -> VoiceDescription {
+    VoiceDescription::new()
+        .gender(gender)
+        .age_range(45..60)
+        .tone("menacing, cold and theatrical")
+        .pace(Pace::Slow)
+        .detail("Low, gravelly voice with deliberate pauses and a hint of amusement")
+}
+
+/// Young child: bright, curious and energetic
+pub fn child(gender: Gender) -> VoiceDescription {
+    VoiceDescription::new()
+        .gender(gender)
+        .age_range(7..10)
+        .tone("bright, curious and cheerful")
+        .pace(Pace::Fast)
+        .detail("High-pitched, playful voice full of energy")
+}
+
+/// Elderly speaker: gentle, wise and slightly raspy
+pub fn elderly(gender: Gender) -> VoiceDescription {
+    VoiceDescription::new()
+        .gender(gender)
+        .age_range(75..85)
+        .tone("gentle, wise and kind")
+        .pace(Pace::Slow)
+        .detail("Slightly raspy, thin voice with a soft tremble")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_can_be_customized() {
+        let description = villain(Gender::Male).accent("Russian").pace(Pace::Moderate);
+
+        assert_eq!(
+            description.to_string(),
+            "Male voice, aged 45 to 60, with a Russian accent. Menacing, cold and theatrical tone. \
+             Moderate pace. Low, gravelly voice with deliberate pauses and a hint of amusement."
+        );
+        assert!(narrator(Gender::Female).to_string().len() >= 20);
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use reqwest::Body;
+use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+
+use crate::voice_clone::audio_mime_type;
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, Sample, StatusResponse, VoiceIdResponse};
+
+/// Builder for Professional Voice Clone creation requests (POST /v1/voices/pvc)
+#[derive(Serialize)]
+pub struct PvcCreateBuilder<'a> {
+    #[serde(skip)]
+    client: &'a ElevenLabsTTVClient,
+    pub name: String,
+    pub language: String,
+    pub description: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+/// Builder for Professional Voice Clone sample uploads (POST /v1/voices/pvc/:voice_id/samples)
+pub struct PvcSamplesBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
+    pub voice_id: String,
+    pub remove_background_noise: Option<bool>,
+    files: Vec<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct TrainPvcRequest<'a> {
+    model_id: Option<&'a str>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start building a Professional Voice Clone creation request
+    ///
+    /// language: Language used in the samples, e.g. "en".
+    pub fn create_pvc_voice<S: Into<String>>(&self, name: S, language: S) -> PvcCreateBuilder<'_> {
+        PvcCreateBuilder {
+            client: self,
+            name: name.into(),
+            language: language.into(),
+            description: None,
+            labels: None,
+        }
+    }
+
+    /// Start building a sample upload for a Professional Voice Clone
+    pub fn add_pvc_samples<S: Into<String>>(&self, voice_id: S) -> PvcSamplesBuilder<'_> {
+        PvcSamplesBuilder {
+            client: self,
+            voice_id: voice_id.into(),
+            remove_background_noise: None,
+            files: Vec::new(),
+        }
+    }
+
+    /// Start training a Professional Voice Clone once its samples are uploaded (and verified)
+    ///
+    /// model_id: Model to train on, defaults to the API's default PVC model when `None`.
+    pub async fn train_pvc_voice(
+        &self,
+        voice_id: &str,
+        model_id: Option<&str>,
+    ) -> Result<StatusResponse, ElevenLabsTTVError> {
+        let url = self.url(&["voices", "pvc", voice_id, "train"])?;
+
+        self.send_json(
+            "voices/pvc/{voice_id}/train",
+            self.inner
+                .client
+                .post(url)
+                .header("Content-Type", "application/json")
+                .json(&TrainPvcRequest { model_id }),
+        )
+        .await
+    }
+}
+
+impl<'a> PvcCreateBuilder<'a> {
+    /// Description of the voice
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a label (metadata) to the voice, e.g. ("accent", "Irish")
+    pub fn label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.labels
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Execute the request, returning the ID of the new (untrained) voice
+    pub async fn execute(self) -> Result<VoiceIdResponse, ElevenLabsTTVError> {
+        let url = self.client.url(&["voices", "pvc"])?;
+
+        self.client
+            .send_json(
+                "voices/pvc",
+                self.client
+                    .inner
+                    .client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .json(&self),
+            )
+            .await
+    }
+}
+
+impl<'a> PvcSamplesBuilder<'a> {
+    /// Add an audio sample from disk. Files are streamed rather than loaded into memory,
+    /// so large studio recordings can be uploaded.
+    pub fn add_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Remove background noise from the samples using audio isolation. Defaults to false
+    pub fn remove_background_noise(mut self, remove_background_noise: bool) -> Self {
+        self.remove_background_noise = Some(remove_background_noise);
+        self
+    }
+
+    /// Execute the upload, returning the created samples
+    pub async fn execute(self) -> Result<Vec<Sample>, ElevenLabsTTVError> {
+        if self.files.is_empty() {
+            return Err(ElevenLabsTTVError::ValidationError(
+                "At least one sample file is required".to_string(),
+            ));
+        }
+
+        let mut form = Form::new();
+
+        if let Some(remove_background_noise) = self.remove_background_noise {
+            form = form.text(
+                "remove_background_noise",
+                remove_background_noise.to_string(),
+            );
+        }
+
+        for path in &self.files {
+            form = form.part("files", streamed_part(path).await?);
+        }
+
+        let url = self
+            .client
+            .url(&["voices", "pvc", &self.voice_id, "samples"])?;
+
+        self.client
+            .send_json(
+                "voices/pvc/{voice_id}/samples",
+                self.client.inner.client.post(url).multipart(form),
+            )
+            .await
+    }
+}
+
+/// Build a multipart part streaming the file from disk in chunks.
+/// Without the `tokio` feature, the file is read into memory instead.
+pub(crate) async fn streamed_part(path: &Path) -> Result<Part, ElevenLabsTTVError> {
+    #[cfg(feature = "tokio")]
+    let (body, length) = {
+        let file = tokio::fs::File::open(path).await?;
+        let length = file.metadata().await?.len();
+        (Body::from(file), length)
+    };
+    #[cfg(not(feature = "tokio"))]
+    let (body, length) = {
+        let data = std::fs::read(path)?;
+        let length = data.len() as u64;
+        (Body::from(data), length)
+    };
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "sample".to_string());
+    let mime_type = audio_mime_type(&file_name);
+
+    Part::stream_with_length(body, length)
+        .file_name(file_name)
+        .mime_str(mime_type)
+        .map_err(ElevenLabsTTVError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_body() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let builder = client
+            .create_pvc_voice("Studio Narrator", "en")
+            .label("studio", "north");
+        let body = serde_json::to_value(&builder).unwrap();
+
+        assert_eq!(body["name"], "Studio Narrator");
+        assert_eq!(body["language"], "en");
+        assert_eq!(body["labels"]["studio"], "north");
+        assert!(body.get("client").is_none());
+    }
+}
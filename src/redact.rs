@@ -0,0 +1,112 @@
+//! Helpers keeping credentials and audio payloads out of logs
+
+use crate::metrics::decoded_base64_len;
+
+/// Mask a secret (e.g. an API key), keeping only its last 4 characters when it is long enough
+pub fn redact_secret(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+
+    let visible: String = chars[chars.len() - 4..].iter().collect();
+    format!("****{}", visible)
+}
+
+/// Summarize a base64 payload by its decoded size instead of printing it
+pub fn redact_base64(encoded: &str) -> String {
+    format!("<base64, {} bytes>", decoded_base64_len(encoded))
+}
+
+/// Summarize a JSON body for logs, replacing base64 audio (`audio_base_64`,
+/// `reference_audio_base64`, ...) with its decoded size and a hash of the encoded payload
+///
+/// The FNV-1a hash tells payloads apart (e.g. whether two requests sent the same reference
+/// clip) without logging them. Bodies that are not JSON are summarized by their size.
+pub fn redact_json_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut json) => {
+            summarize_base64_audio(&mut json);
+            json.to_string()
+        }
+        Err(_) => format!("<{} bytes>", body.len()),
+    }
+}
+
+/// Whether a JSON field holds base64 audio, e.g. `audio_base_64` or `reference_audio_base64`
+pub(crate) fn is_base64_audio_field(name: &str) -> bool {
+    name.contains("audio") && name.contains("base")
+}
+
+fn summarize_base64_audio(json: &mut serde_json::Value) {
+    match json {
+        serde_json::Value::Object(object) => {
+            for (name, value) in object {
+                match value {
+                    serde_json::Value::String(encoded) if is_base64_audio_field(name) => {
+                        *encoded = format!(
+                            "<base64, {} bytes, fnv1a {:016x}>",
+                            decoded_base64_len(encoded),
+                            fnv1a(encoded.as_bytes())
+                        );
+                    }
+                    _ => summarize_base64_audio(value),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(summarize_base64_audio),
+        _ => {}
+    }
+}
+
+/// 64-bit FNV-1a, stable across runs and platforms unlike `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Mask the values of secret-looking query parameters (keys, tokens, signatures...)
+pub fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if is_secret_name(name) => format!("{}=****", name),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn is_secret_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ["key", "token", "secret", "signature", "auth", "password"]
+        .iter()
+        .any(|secret| name.contains(secret))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        assert_eq!(redact_secret("sk_1234567890abcd"), "****abcd");
+        assert_eq!(redact_secret("short"), "****");
+        assert_eq!(redact_base64("aGVsbG8="), "<base64, 5 bytes>");
+        assert_eq!(
+            redact_query("output_format=mp3_44100_128&xi-api-key=sk_123"),
+            "output_format=mp3_44100_128&xi-api-key=****"
+        );
+    }
+
+    #[test]
+    fn test_redact_json_body() {
+        let body = br#"{"previews":[{"audio_base_64":"aGVsbG8=","generated_voice_id":"gen-1"}]}"#;
+        assert_eq!(
+            redact_json_body(body),
+            r#"{"previews":[{"audio_base_64":"<base64, 5 bytes, fnv1a 4a713a01f4816aee>","generated_voice_id":"gen-1"}]}"#
+        );
+        assert_eq!(redact_json_body(b"\xff\xfb audio"), "<8 bytes>");
+    }
+}
@@ -0,0 +1,313 @@
+use std::path::Path;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+#[cfg(feature = "tokio")]
+use {
+    bytes::Bytes,
+    futures_util::{StreamExt, stream},
+    reqwest::Body,
+    reqwest::header::CONTENT_LENGTH,
+    tokio::io::AsyncReadExt,
+};
+
+#[cfg(feature = "tokio")]
+use crate::{ElevenLabsTTVClient, TTVDesignVoiceRequest, TTVDesignVoiceResponse, json_body};
+use crate::{ElevenLabsTTVError, ParameterViolation};
+
+/// Largest reference clip sent to the API, in (decoded) bytes
+pub const MAX_REFERENCE_AUDIO_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Longest reference clip sent to the API, in seconds. Only checked for WAV clips,
+/// whose duration is known from their header.
+pub const MAX_REFERENCE_AUDIO_SECS: f64 = 60.0;
+
+/// Number of leading bytes needed to detect the format (and WAV duration) of a clip
+const HEADER_LENGTH: usize = 48;
+
+/// Bytes of audio encoded per chunk. A multiple of 3, so chunks concatenate to valid base64.
+#[cfg(feature = "tokio")]
+const CHUNK_SIZE: usize = 3 * 16 * 1024;
+
+#[cfg(feature = "tokio")]
+impl ElevenLabsTTVClient {
+    /// Execute a Design Voice request with the reference audio streamed from a file
+    ///
+    /// The file is read and base64-encoded chunk by chunk while the JSON body is sent, instead
+    /// of building the whole encoded clip in memory. Such requests cannot be replayed, so they
+    /// are not retried with another API key.
+    pub(crate) async fn execute_design_voice_streamed(
+        &self,
+        request: &TTVDesignVoiceRequest,
+        reference_audio_file: &Path,
+    ) -> Result<TTVDesignVoiceResponse, ElevenLabsTTVError> {
+        let mut file = tokio::fs::File::open(reference_audio_file).await?;
+        let file_length = file.metadata().await?.len();
+
+        let mut header = Vec::with_capacity(HEADER_LENGTH);
+        (&mut file)
+            .take(HEADER_LENGTH as u64)
+            .read_to_end(&mut header)
+            .await?;
+        crate::validation::into_result(violations(file_length, &header))?;
+        let file = {
+            use tokio::io::AsyncSeekExt;
+            file.seek(std::io::SeekFrom::Start(0)).await?;
+            file
+        };
+
+        // Body without the reference audio, then the base64 string spliced in as last field
+        let body = json_body(
+            request,
+            &["output_format", "extra_query", "reference_audio_base64"],
+        )?;
+        let mut prefix = serde_json::to_vec(&body)?;
+        prefix.pop(); // closing brace
+        if prefix.len() > 1 {
+            prefix.push(b',');
+        }
+        prefix.extend_from_slice(br#""reference_audio_base64":""#);
+        let suffix: &[u8] = b"\"}";
+
+        let content_length =
+            prefix.len() as u64 + file_length.div_ceil(3) * 4 + suffix.len() as u64;
+        let body = stream::once(async { Ok(Bytes::from(prefix)) })
+            .chain(base64_chunks(file))
+            .chain(stream::once(async move {
+                Ok::<_, std::io::Error>(Bytes::from_static(suffix))
+            }));
+
+        let http_request = self
+            .design_voice_request(request)?
+            .header(CONTENT_LENGTH, content_length)
+            .body(Body::wrap_stream(body));
+
+        self.send_design_voice(http_request).await
+    }
+}
+
+/// Base64 of the file, read `CHUNK_SIZE` bytes at a time
+#[cfg(feature = "tokio")]
+fn base64_chunks(
+    file: tokio::fs::File,
+) -> impl futures_util::Stream<Item = Result<Bytes, std::io::Error>> {
+    stream::unfold(Some(file), |file| async move {
+        let mut file = file?;
+        let mut buffer = vec![0; CHUNK_SIZE];
+        let mut filled = 0;
+        while filled < CHUNK_SIZE {
+            match file.read(&mut buffer[filled..]).await {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(e) => return Some((Err(e), None)),
+            }
+        }
+
+        if filled == 0 {
+            return None;
+        }
+        let next = (filled == CHUNK_SIZE).then_some(file);
+        Some((Ok(Bytes::from(STANDARD.encode(&buffer[..filled]))), next))
+    })
+}
+
+/// Sample rate reference clips are converted to by `prepare_wav`
+#[cfg(feature = "audio")]
+pub const PREFERRED_SAMPLE_RATE: u32 = 44100;
+
+/// Fade-out applied by `prepare_wav` when trimming a clip to `MAX_REFERENCE_AUDIO_SECS`
+#[cfg(feature = "audio")]
+pub const TRIM_FADE_OUT: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Convert a 16-bit PCM WAV reference clip to mono at `PREFERRED_SAMPLE_RATE`, trimmed to
+/// `MAX_REFERENCE_AUDIO_SECS` with a `TRIM_FADE_OUT` fade-out (`audio` feature)
+///
+/// Use the result with `.reference_audio_base64(...)`, e.g. to turn a long 48 kHz stereo
+/// studio recording into a clip the API accepts, rather than having it rejected or silently
+/// truncated. Fails with a `ValidationError` if the prepared clip still exceeds the size limit.
+#[cfg(feature = "audio")]
+pub fn prepare_wav(wav: &[u8]) -> Result<Vec<u8>, ElevenLabsTTVError> {
+    let audio = crate::PcmAudio::from_wav(wav)?
+        .to_mono()
+        .resample(PREFERRED_SAMPLE_RATE)
+        .trim(
+            std::time::Duration::from_secs_f64(MAX_REFERENCE_AUDIO_SECS),
+            TRIM_FADE_OUT,
+        );
+    let prepared = audio.to_wav()?;
+
+    crate::validation::into_result(violations(prepared.len() as u64, &prepared))?;
+    Ok(prepared)
+}
+
+/// Problems with a reference clip of `length` bytes starting with `header`
+pub(crate) fn violations(length: u64, header: &[u8]) -> Vec<ParameterViolation> {
+    let mut violations = Vec::new();
+
+    if length == 0 {
+        violations.push(ParameterViolation {
+            field: "reference_audio",
+            value: "0 bytes".to_string(),
+            allowed: "a non-empty audio clip".to_string(),
+        });
+        return violations;
+    }
+    if length > MAX_REFERENCE_AUDIO_BYTES {
+        violations.push(ParameterViolation {
+            field: "reference_audio",
+            value: format!("{} bytes", length),
+            allowed: format!("at most {} bytes", MAX_REFERENCE_AUDIO_BYTES),
+        });
+    }
+    match detect_format(header) {
+        None => violations.push(ParameterViolation {
+            field: "reference_audio",
+            value: "unrecognized audio format".to_string(),
+            allowed: "MP3, WAV, FLAC, OGG or M4A audio".to_string(),
+        }),
+        Some("wav") => {
+            if let Some(secs) = wav_duration_secs(length, header)
+                && secs > MAX_REFERENCE_AUDIO_SECS
+            {
+                violations.push(ParameterViolation {
+                    field: "reference_audio",
+                    value: format!("{:.1} seconds", secs),
+                    allowed: format!("at most {} seconds", MAX_REFERENCE_AUDIO_SECS),
+                });
+            }
+        }
+        Some(_) => {}
+    }
+
+    violations
+}
+
+/// Problems with base64-encoded reference audio, see `violations`
+pub(crate) fn base64_violations(reference_audio_base64: &str) -> Vec<ParameterViolation> {
+    let prefix_length = reference_audio_base64.len().min(HEADER_LENGTH / 3 * 4);
+    let Ok(header) = STANDARD.decode(&reference_audio_base64.as_bytes()[..prefix_length]) else {
+        return vec![ParameterViolation {
+            field: "reference_audio_base64",
+            value: "invalid base64".to_string(),
+            allowed: "standard base64 with padding".to_string(),
+        }];
+    };
+
+    violations(
+        crate::metrics::decoded_base64_len(reference_audio_base64),
+        &header,
+    )
+}
+
+/// Container format of a clip from its magic bytes
+pub(crate) fn detect_format(header: &[u8]) -> Option<&'static str> {
+    match header {
+        [b'I', b'D', b'3', ..] => Some("mp3"),
+        [0xff, second, ..] if second & 0xe0 == 0xe0 => Some("mp3"),
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'A',
+            b'V',
+            b'E',
+            ..,
+        ] => Some("wav"),
+        [b'f', b'L', b'a', b'C', ..] => Some("flac"),
+        [b'O', b'g', b'g', b'S', ..] => Some("ogg"),
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some("m4a"),
+        _ => None,
+    }
+}
+
+/// Approximate duration of a canonical WAV file from its header's byte rate
+fn wav_duration_secs(length: u64, header: &[u8]) -> Option<f64> {
+    let byte_rate = u32::from_le_bytes(header.get(28..32)?.try_into().ok()?);
+    if byte_rate == 0 {
+        return None;
+    }
+    Some(length.saturating_sub(44) as f64 / byte_rate as f64)
+}
+
+/// Base64 of a reference audio file, loaded into memory
+pub(crate) fn encode_file(path: &Path) -> Result<String, ElevenLabsTTVError> {
+    Ok(STANDARD.encode(std::fs::read(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_base64_chunks_match_one_shot_encoding() {
+        let data: Vec<u8> = (0..CHUNK_SIZE * 2 + 7).map(|i| (i % 251) as u8).collect();
+        let path = std::env::temp_dir().join(format!("ttv-ref-{}.bin", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+
+        let file = tokio::fs::File::open(&path).await.unwrap();
+        let chunks: Vec<Bytes> = base64_chunks(file)
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), STANDARD.encode(&data).into_bytes());
+    }
+
+    #[test]
+    fn test_reference_audio_violations() {
+        assert!(base64_violations("SUQzBAAAAAAAAA==").is_empty());
+        assert_eq!(base64_violations("aGVsbG8gd29ybGQ=").len(), 1);
+    }
+
+    #[test]
+    fn test_base64_violations_non_ascii() {
+        // The decoded prefix ends inside a character, which must not be sliced as a str
+        let prefix_length = HEADER_LENGTH / 3 * 4;
+        for input in [
+            "é".repeat(40),
+            format!("{}é", "A".repeat(prefix_length - 1)),
+            format!("SUQz{}🎙", "A".repeat(prefix_length - 6)),
+        ] {
+            let violations = base64_violations(&input);
+            assert_eq!(violations.len(), 1, "{}", input);
+            assert_eq!(violations[0].value, "invalid base64");
+        }
+    }
+
+    #[test]
+    fn test_wav_header_violations() {
+        // 16 kHz mono 16-bit WAV header claiming 2 minutes of audio
+        let mut wav = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+        wav.extend_from_slice(&[16, 0, 0, 0, 1, 0, 1, 0]);
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&32000u32.to_le_bytes());
+        let violations = violations(44 + 120 * 32000, &wav);
+        assert_eq!(violations[0].value, "120.0 seconds");
+    }
+
+    #[cfg(feature = "audio")]
+    #[test]
+    fn test_prepare_wav_trims_long_clips() {
+        let recording = crate::PcmAudio {
+            sample_rate: PREFERRED_SAMPLE_RATE,
+            channels: 2,
+            samples: vec![1000; 2 * PREFERRED_SAMPLE_RATE as usize * 65],
+        };
+
+        let prepared = prepare_wav(&recording.to_wav().unwrap()).unwrap();
+        let prepared = crate::PcmAudio::from_wav(&prepared).unwrap();
+        assert_eq!(prepared.channels, 1);
+        assert_eq!(prepared.sample_rate, PREFERRED_SAMPLE_RATE);
+        assert_eq!(prepared.duration_secs(), MAX_REFERENCE_AUDIO_SECS);
+        assert_eq!(prepared.samples.last(), Some(&0));
+    }
+}
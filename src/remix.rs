@@ -0,0 +1,353 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, RemixingSessionIterationsResponse,
+    TTVDesignVoiceResponse,
+};
+
+/// Builder for remixing a generated voice (POST /v1/text-to-voice/:generated_voice_id/remix),
+/// created with `ElevenLabsTTVClient::remix`
+#[derive(Serialize)]
+pub struct RemixBuilder<'a> {
+    #[serde(skip)]
+    client: &'a ElevenLabsTTVClient,
+    #[serde(skip)]
+    pub generated_voice_id: String,
+    /// The remix prompt, e.g. "make it 10 years older"
+    pub voice_description: String,
+    #[serde(skip)]
+    pub output_format: Option<String>,
+    pub text: Option<String>,
+    pub auto_generate_text: Option<bool>,
+    pub loudness: Option<f32>,
+    pub seed: Option<u32>,
+    pub guidance_scale: Option<u32>,
+    pub stream_previews: Option<bool>,
+    pub remixing_session_id: Option<String>,
+    pub remixing_session_iteration_id: Option<String>,
+    pub prompt_strength: Option<f32>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start building a remix of a generated voice, e.g.
+    /// `client.remix(generated_voice_id, "make it 10 years older").execute().await?`
+    ///
+    /// The remix starts a new remixing session, and `execute` returns it as a `RemixSession`:
+    /// remixes built with `RemixSession::remix` are attached to the same session, as new
+    /// iterations, so they show up together in `list_remixing_session_iterations`. The API
+    /// does not return session IDs, so the ID of a new session is generated client-side.
+    pub fn remix<S: Into<String>>(&self, generated_voice_id: S, prompt: S) -> RemixBuilder<'_> {
+        RemixBuilder {
+            client: self,
+            generated_voice_id: generated_voice_id.into(),
+            voice_description: prompt.into(),
+            output_format: None,
+            text: None,
+            auto_generate_text: None,
+            loudness: None,
+            seed: None,
+            guidance_scale: None,
+            stream_previews: None,
+            remixing_session_id: None,
+            remixing_session_iteration_id: None,
+            prompt_strength: None,
+        }
+    }
+
+    /// List the iterations of a remixing session and the generated voice IDs of each
+    ///
+    /// Wraps GET /v1/text-to-voice/remixing-sessions/:remixing_session_id/iterations.
+    pub async fn list_remixing_session_iterations(
+        &self,
+        remixing_session_id: &str,
+    ) -> Result<RemixingSessionIterationsResponse, ElevenLabsTTVError> {
+        let url = self.url(&[
+            "text-to-voice",
+            "remixing-sessions",
+            remixing_session_id,
+            "iterations",
+        ])?;
+
+        self.send_json(
+            "text-to-voice/remixing-sessions/{remixing_session_id}/iterations",
+            self.inner.client.get(url),
+        )
+        .await
+    }
+}
+
+impl<'a> RemixBuilder<'a> {
+    /// Output format of the generated audio, see `TextToVoiceDesignVoiceBuilder::output_format`.
+    /// Defaults to mp3_44100_128.
+    pub fn output_format<S: Into<String>>(mut self, output_format: S) -> Self {
+        self.output_format = Some(output_format.into());
+        self
+    }
+
+    /// Text to generate the previews with (100 to 1000 characters).
+    /// Generated automatically when not set.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Volume of the remixed voice, -1 (quietest) to 1 (loudest)
+    pub fn loudness(mut self, loudness: f32) -> Self {
+        self.loudness = Some(loudness);
+        self
+    }
+
+    /// Best-effort deterministic sampling
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// How closely to follow the prompt, 0 to 100
+    pub fn guidance_scale(mut self, guidance_scale: u32) -> Self {
+        self.guidance_scale = Some(guidance_scale);
+        self
+    }
+
+    /// Only return generated voice IDs, to be streamed via `stream_preview`. Defaults to false
+    pub fn stream_previews(mut self, stream_previews: bool) -> Self {
+        self.stream_previews = Some(stream_previews);
+        self
+    }
+
+    /// Balance of the remix prompt versus the original voice, 0 to 1
+    pub fn prompt_strength(mut self, prompt_strength: f32) -> Self {
+        self.prompt_strength = Some(prompt_strength);
+        self
+    }
+
+    /// Attach the remix to an existing remixing session instead of starting a new one
+    pub fn remixing_session_id<S: Into<String>>(mut self, remixing_session_id: S) -> Self {
+        self.remixing_session_id = Some(remixing_session_id.into());
+        self
+    }
+
+    /// Attach the remix to an existing iteration instead of creating a new one
+    pub fn remixing_session_iteration_id<S: Into<String>>(
+        mut self,
+        remixing_session_iteration_id: S,
+    ) -> Self {
+        self.remixing_session_iteration_id = Some(remixing_session_iteration_id.into());
+        self
+    }
+
+    /// Generate the preview text automatically. Defaults to true when `text` is not set
+    pub fn auto_generate_text(mut self, auto_generate_text: bool) -> Self {
+        self.auto_generate_text = Some(auto_generate_text);
+        self
+    }
+
+    /// Validate and execute the remix, returning the new previews with their session
+    pub async fn execute(mut self) -> Result<RemixSession<'a>, ElevenLabsTTVError> {
+        self.validate()?;
+        let url = self
+            .client
+            .url(&["text-to-voice", &self.generated_voice_id, "remix"])?;
+        let output_format = self
+            .output_format
+            .clone()
+            .unwrap_or_else(|| "mp3_44100_128".to_string()); // Default to: mp3_44100_128
+
+        if self.auto_generate_text.is_none() {
+            self.auto_generate_text = Some(self.text.is_none());
+        }
+        let remixing_session_id = self
+            .remixing_session_id
+            .get_or_insert_with(new_remixing_session_id)
+            .clone();
+
+        let response: TTVDesignVoiceResponse = self
+            .client
+            .send_json(
+                "text-to-voice/{generated_voice_id}/remix",
+                self.client
+                    .inner
+                    .client
+                    .post(url)
+                    .query(&[("output_format", output_format)])
+                    .header("Content-Type", "application/json")
+                    .json(&self),
+            )
+            .await?;
+
+        let audio_bytes = response
+            .previews
+            .iter()
+            .map(|preview| crate::metrics::decoded_base64_len(&preview.audio_base_64))
+            .sum();
+        self.client
+            .record_audio_bytes("text-to-voice/{generated_voice_id}/remix", audio_bytes);
+
+        Ok(RemixSession {
+            client: self.client,
+            remixing_session_id,
+            response,
+        })
+    }
+}
+
+/// A remixing session, returned by `RemixBuilder::execute` with the previews of the remix
+#[derive(Debug, Clone)]
+pub struct RemixSession<'a> {
+    client: &'a ElevenLabsTTVClient,
+    pub remixing_session_id: String,
+    /// The previews generated by the remix
+    pub response: TTVDesignVoiceResponse,
+}
+
+impl<'a> RemixSession<'a> {
+    /// Start building another remix in this session, e.g. of one of `response.previews`.
+    /// It is attached as a new iteration unless `remixing_session_iteration_id` is set
+    pub fn remix<S: Into<String>>(&self, generated_voice_id: S, prompt: S) -> RemixBuilder<'a> {
+        self.client
+            .remix(generated_voice_id, prompt)
+            .remixing_session_id(self.remixing_session_id.clone())
+    }
+
+    /// The iterations of this session so far, see `list_remixing_session_iterations`
+    pub async fn iterations(
+        &self,
+    ) -> Result<RemixingSessionIterationsResponse, ElevenLabsTTVError> {
+        self.client
+            .list_remixing_session_iterations(&self.remixing_session_id)
+            .await
+    }
+
+    pub fn into_response(self) -> TTVDesignVoiceResponse {
+        self.response
+    }
+}
+
+/// A new remixing session ID, unique across clients and processes
+fn new_remixing_session_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    let random = RandomState::new().hash_one((nanos, COUNTER.fetch_add(1, Ordering::Relaxed)));
+    format!("{:x}{:016x}", nanos, random)
+}
+
+impl RemixingSessionIterationsResponse {
+    /// The iteration a generated voice belongs to, if any
+    pub fn iteration_of(&self, generated_voice_id: &str) -> Option<&str> {
+        self.iterations
+            .iter()
+            .find(|iteration| {
+                iteration
+                    .generated_voice_ids
+                    .iter()
+                    .any(|id| id == generated_voice_id)
+            })
+            .map(|iteration| iteration.remixing_session_iteration_id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iteration_of() {
+        let response: RemixingSessionIterationsResponse =
+            serde_json::from_value(serde_json::json!({
+                "remixing_session_id": "session-1",
+                "iterations": [
+                    {"remixing_session_iteration_id": "it-1", "generated_voice_ids": ["a", "b"]},
+                    {"remixing_session_iteration_id": "it-2", "generated_voice_ids": ["c"],
+                     "voice_description": "make it older", "created_at_unix": 1714204800}
+                ]
+            }))
+            .unwrap();
+
+        assert_eq!(response.iteration_of("c"), Some("it-2"));
+        assert_eq!(response.iteration_of("a"), Some("it-1"));
+        assert_eq!(response.iteration_of("z"), None);
+    }
+
+    #[test]
+    fn test_remix_violations() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        assert!(
+            client
+                .remix("gen-1", "make it older")
+                .violations()
+                .is_empty()
+        );
+
+        let fields: Vec<&str> = client
+            .remix("gen-1", " ")
+            .text("Too short")
+            .loudness(2.0)
+            .guidance_scale(101)
+            .violations()
+            .iter()
+            .map(|violation| violation.field)
+            .collect();
+        assert_eq!(
+            fields,
+            ["voice_description", "text", "loudness", "guidance_scale"]
+        );
+    }
+
+    #[test]
+    fn test_new_remixing_session_ids_are_unique() {
+        let first = new_remixing_session_id();
+        assert!(!first.is_empty());
+        assert_ne!(first, new_remixing_session_id());
+    }
+
+    #[tokio::test]
+    async fn test_remix_keeps_caller_settings() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/gen-1/remix"))
+            .and(body_json(serde_json::json!({
+                "voice_description": "make it older",
+                "text": null,
+                "auto_generate_text": false,
+                "loudness": null,
+                "seed": null,
+                "guidance_scale": null,
+                "stream_previews": null,
+                "remixing_session_id": "session-1",
+                "remixing_session_iteration_id": null,
+                "prompt_strength": null
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "Hello"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let session = client
+            .remix("gen-1", "make it older")
+            .auto_generate_text(false)
+            .remixing_session_id("session-1")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(session.remixing_session_id, "session-1");
+
+        let error = client.remix("gen-1", "").execute().await.unwrap_err();
+        assert!(matches!(error, ElevenLabsTTVError::ValidationError(_)));
+    }
+}
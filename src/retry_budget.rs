@@ -0,0 +1,116 @@
+//! Retry budget capping the share of requests that are retries, so retrying never amplifies
+//! an outage, e.g. batch jobs hammering the design endpoint while it fails
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Maximum share of retries over a sliding window, see
+/// `ElevenLabsTTVClientBuilder::retry_budget`
+///
+/// `RetryBudget::new(0.2, Duration::from_secs(10))` allows at most one retry per five
+/// requests over the last 10 seconds, plus `min_retries` so a mostly idle client can still
+/// retry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryBudget {
+    /// Maximum retries per (first-attempt) request
+    pub ratio: f64,
+    pub window: Duration,
+    /// Retries allowed per window regardless of `ratio`
+    pub min_retries: u32,
+}
+
+impl RetryBudget {
+    pub fn new(ratio: f64, window: Duration) -> Self {
+        Self {
+            ratio,
+            window,
+            min_retries: 1,
+        }
+    }
+
+    /// Retries allowed per window regardless of `ratio`. Defaults to 1
+    pub fn min_retries(mut self, min_retries: u32) -> Self {
+        self.min_retries = min_retries;
+        self
+    }
+}
+
+impl Default for RetryBudget {
+    /// 10% of requests over 10 seconds
+    fn default() -> Self {
+        Self::new(0.1, Duration::from_secs(10))
+    }
+}
+
+/// Requests and retries in the current window, shared by all clones of a client
+#[derive(Debug)]
+pub(crate) struct RetryBudgetTracker {
+    budget: RetryBudget,
+    requests: Mutex<VecDeque<Instant>>,
+    retries: Mutex<VecDeque<Instant>>,
+}
+
+impl RetryBudgetTracker {
+    pub(crate) fn new(budget: RetryBudget) -> Self {
+        Self {
+            budget,
+            requests: Mutex::new(VecDeque::new()),
+            retries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Count a first attempt
+    pub(crate) fn record_request(&self) {
+        let mut requests = self.requests.lock().unwrap();
+        prune(&mut requests, self.budget.window);
+        requests.push_back(Instant::now());
+    }
+
+    /// Whether a retry fits in the budget, counting it if so
+    pub(crate) fn try_retry(&self) -> bool {
+        let requests = {
+            let mut requests = self.requests.lock().unwrap();
+            prune(&mut requests, self.budget.window);
+            requests.len()
+        };
+        let mut retries = self.retries.lock().unwrap();
+        prune(&mut retries, self.budget.window);
+
+        let allowed =
+            ((requests as f64 * self.budget.ratio) as usize).max(self.budget.min_retries as usize);
+        if retries.len() >= allowed {
+            return false;
+        }
+        retries.push_back(Instant::now());
+        true
+    }
+}
+
+/// Drop the times older than `window`
+fn prune(times: &mut VecDeque<Instant>, window: Duration) {
+    while times.front().is_some_and(|time| time.elapsed() > window) {
+        times.pop_front();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retries_are_capped_by_the_budget() {
+        let tracker =
+            RetryBudgetTracker::new(RetryBudget::new(0.2, Duration::from_secs(60)).min_retries(1));
+
+        tracker.record_request();
+        assert!(tracker.try_retry());
+        assert!(!tracker.try_retry());
+
+        for _ in 0..9 {
+            tracker.record_request();
+        }
+        assert!(tracker.try_retry());
+        assert!(!tracker.try_retry());
+    }
+}
@@ -0,0 +1,206 @@
+#[cfg(feature = "tokio")]
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "tokio")]
+use crate::SeparationStatus;
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, SpeakerSeparation, StatusResponse};
+
+impl ElevenLabsTTVClient {
+    /// Delete a sample from a voice, e.g. to prune a bad reference take
+    ///
+    /// Wraps DELETE /v1/voices/:voice_id/samples/:sample_id.
+    pub async fn delete_sample(
+        &self,
+        voice_id: &str,
+        sample_id: &str,
+    ) -> Result<StatusResponse, ElevenLabsTTVError> {
+        let url = self.url(&["voices", voice_id, "samples", sample_id])?;
+
+        self.send_json(
+            "voices/{voice_id}/samples/{sample_id}",
+            self.inner.client.delete(url),
+        )
+        .await
+    }
+
+    /// Start separating the speakers of a voice sample
+    ///
+    /// Wraps POST /v1/voices/:voice_id/samples/:sample_id/separate-speakers.
+    /// Poll the result with `get_speaker_separation` or `wait_for_speaker_separation`.
+    pub async fn start_speaker_separation(
+        &self,
+        voice_id: &str,
+        sample_id: &str,
+    ) -> Result<StatusResponse, ElevenLabsTTVError> {
+        let url = self.url(&[
+            "voices",
+            voice_id,
+            "samples",
+            sample_id,
+            "separate-speakers",
+        ])?;
+
+        self.send_json(
+            "voices/{voice_id}/samples/{sample_id}/separate-speakers",
+            self.inner.client.post(url),
+        )
+        .await
+    }
+
+    /// Get the speaker separation status and detected speakers of a voice sample
+    ///
+    /// Wraps GET /v1/voices/:voice_id/samples/:sample_id/speakers.
+    pub async fn get_speaker_separation(
+        &self,
+        voice_id: &str,
+        sample_id: &str,
+    ) -> Result<SpeakerSeparation, ElevenLabsTTVError> {
+        let url = self.url(&["voices", voice_id, "samples", sample_id, "speakers"])?;
+
+        self.send_json(
+            "voices/{voice_id}/samples/{sample_id}/speakers",
+            self.inner.client.get(url),
+        )
+        .await
+    }
+
+    /// Poll `get_speaker_separation` every `poll_interval` until the separation
+    /// is completed or failed, and return the final state. Requires the `tokio` feature (default).
+    ///
+    /// Fails with a `TimeoutError` if the separation has not finished after `timeout`, e.g.
+    /// when it was never started.
+    #[cfg(feature = "tokio")]
+    pub async fn wait_for_speaker_separation(
+        &self,
+        voice_id: &str,
+        sample_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<SpeakerSeparation, ElevenLabsTTVError> {
+        let started = Instant::now();
+        loop {
+            let separation = self.get_speaker_separation(voice_id, sample_id).await?;
+
+            if matches!(
+                separation.status,
+                SeparationStatus::Completed | SeparationStatus::Failed
+            ) {
+                return Ok(separation);
+            }
+            if started.elapsed() + poll_interval > timeout {
+                return Err(ElevenLabsTTVError::TimeoutError(format!(
+                    "speaker separation of sample {} is still {:?} after {:?}",
+                    sample_id,
+                    separation.status,
+                    started.elapsed()
+                )));
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_sample() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/voices/voice-1/samples/sample-1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let response = client.delete_sample("voice-1", "sample-1").await.unwrap();
+
+        assert_eq!(response.status, "ok");
+    }
+
+    #[cfg(feature = "tokio")]
+    fn separation(status: &str) -> serde_json::Value {
+        serde_json::json!({"voice_id": "voice-1", "sample_id": "sample-1", "status": status})
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_wait_for_speaker_separation() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1/samples/sample-1/speakers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(separation("not_started")))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1/samples/sample-1/speakers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(separation("pending")))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1/samples/sample-1/speakers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(separation("completed")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let separation = client
+            .wait_for_speaker_separation(
+                "voice-1",
+                "sample-1",
+                Duration::from_millis(1),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(separation.status, SeparationStatus::Completed));
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_wait_for_speaker_separation_times_out() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1/samples/sample-1/speakers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(separation("pending")))
+            .mount(&server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let error = client
+            .wait_for_speaker_separation(
+                "voice-1",
+                "sample-1",
+                Duration::from_millis(10),
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&error, ElevenLabsTTVError::TimeoutError(msg) if msg.contains("Pending")),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+#[cfg(feature = "tokio")]
+use std::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+#[cfg(feature = "tokio")]
+use tokio::sync::Notify;
+
+use crate::ElevenLabsTTVClient;
+
+/// Concurrency limits reported by the API in the `current-concurrent-requests` and
+/// `maximum-concurrent-requests` response headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    pub current_concurrent_requests: u32,
+    pub maximum_concurrent_requests: u32,
+}
+
+impl RateLimitInfo {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| -> Option<u32> { headers.get(name)?.to_str().ok()?.parse().ok() };
+
+        Some(Self {
+            current_concurrent_requests: header("current-concurrent-requests")?,
+            maximum_concurrent_requests: header("maximum-concurrent-requests")?,
+        })
+    }
+
+    /// Share of the concurrency limit in use, from 0.0 to 1.0 (or more when over the limit)
+    pub fn utilization(&self) -> f64 {
+        if self.maximum_concurrent_requests == 0 {
+            1.0
+        } else {
+            self.current_concurrent_requests as f64 / self.maximum_concurrent_requests as f64
+        }
+    }
+}
+
+/// Cooldown after a 429 response without a usable `Retry-After` header
+pub const DEFAULT_RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(1);
+
+/// Longest cooldown after a 429 response, whatever its `Retry-After` header asks, so a bogus
+/// value cannot stall every clone of a client for hours
+pub const MAX_RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(300);
+
+/// Latest `RateLimitInfo` seen by a client and the cooldown after its last 429 response,
+/// shared by all its clones
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitTracker {
+    latest: Mutex<Option<RateLimitInfo>>,
+    cooldown_until: Mutex<Option<Instant>>,
+}
+
+impl RateLimitTracker {
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        if let Some(info) = RateLimitInfo::from_headers(headers) {
+            *self.latest.lock().unwrap() = Some(info);
+        }
+    }
+
+    pub(crate) fn latest(&self) -> Option<RateLimitInfo> {
+        *self.latest.lock().unwrap()
+    }
+
+    /// Start (or extend) the cooldown after a 429 response, for as long as its `Retry-After`
+    /// header asks (up to `MAX_RATE_LIMIT_COOLDOWN`), or `DEFAULT_RATE_LIMIT_COOLDOWN`
+    pub(crate) fn rate_limited(&self, headers: &HeaderMap) {
+        let retry_after = headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok()?.trim().parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_RATE_LIMIT_COOLDOWN)
+            .min(MAX_RATE_LIMIT_COOLDOWN);
+        let now = Instant::now();
+        let until = now.checked_add(retry_after).unwrap_or(now);
+
+        let mut cooldown_until = self.cooldown_until.lock().unwrap();
+        if cooldown_until.is_none_or(|current| current < until) {
+            *cooldown_until = Some(until);
+        }
+    }
+
+    /// Time left before requests may be sent again, if a cooldown is active
+    pub(crate) fn cooldown_remaining(&self) -> Option<Duration> {
+        let until = (*self.cooldown_until.lock().unwrap())?;
+        until
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// Concurrency limits from the most recent response that reported them
+    pub fn rate_limit_info(&self) -> Option<RateLimitInfo> {
+        self.inner.rate_limits.latest()
+    }
+
+    /// Time left in the cooldown started by the last 429 response seen by this client or any
+    /// of its clones, during which requests wait (or, without the `tokio` feature, fail with
+    /// `RateLimitError`) instead of being sent
+    pub fn rate_limit_cooldown(&self) -> Option<Duration> {
+        self.inner.rate_limits.cooldown_remaining()
+    }
+}
+
+/// Runs jobs (e.g. design requests of a batch) with a concurrency that adapts to the
+/// limits reported by the API, slowing down as they are approached instead of running
+/// into 429 responses
+///
+/// Until the API has reported its limits, up to `max_concurrency` jobs run at once.
+/// Afterwards at most `maximum_concurrent_requests - 1` run at once (leaving room for
+/// other users of the same account), and starting a job is delayed by `slowdown` while
+/// utilization is at or above `slowdown_threshold`. Requires the `tokio` feature (default).
+#[cfg(feature = "tokio")]
+pub struct AdaptiveScheduler {
+    client: ElevenLabsTTVClient,
+    in_flight: AtomicUsize,
+    released: Notify,
+    pub max_concurrency: usize,
+    pub slowdown_threshold: f64,
+    pub slowdown: Duration,
+}
+
+#[cfg(feature = "tokio")]
+impl AdaptiveScheduler {
+    pub fn new(client: &ElevenLabsTTVClient, max_concurrency: usize) -> Self {
+        Self {
+            client: client.clone(),
+            in_flight: AtomicUsize::new(0),
+            released: Notify::new(),
+            max_concurrency: max_concurrency.max(1),
+            slowdown_threshold: 0.8,
+            slowdown: Duration::from_millis(250),
+        }
+    }
+
+    /// Utilization at which job starts are delayed. Defaults to 0.8
+    pub fn slowdown_threshold(mut self, slowdown_threshold: f64) -> Self {
+        self.slowdown_threshold = slowdown_threshold;
+        self
+    }
+
+    /// Delay before starting a job while utilization is high. Defaults to 250ms
+    pub fn slowdown(mut self, slowdown: Duration) -> Self {
+        self.slowdown = slowdown;
+        self
+    }
+
+    /// Number of jobs currently allowed to run at once
+    pub fn allowed_concurrency(&self) -> usize {
+        match self.client.rate_limit_info() {
+            Some(info) => (info.maximum_concurrent_requests as usize)
+                .saturating_sub(1)
+                .clamp(1, self.max_concurrency),
+            None => self.max_concurrency,
+        }
+    }
+
+    /// Number of jobs currently running
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Wait for a free slot, then run the job
+    pub async fn run<F: Future>(&self, job: F) -> F::Output {
+        let _slot = self.acquire().await;
+
+        if self
+            .client
+            .rate_limit_info()
+            .is_some_and(|info| info.utilization() >= self.slowdown_threshold)
+        {
+            tokio::time::sleep(self.slowdown).await;
+        }
+
+        job.await
+    }
+
+    async fn acquire(&self) -> Slot<'_> {
+        loop {
+            let released = self.released.notified();
+            let in_flight = self.in_flight.load(Ordering::Acquire);
+            if in_flight < self.allowed_concurrency()
+                && self
+                    .in_flight
+                    .compare_exchange(
+                        in_flight,
+                        in_flight + 1,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    )
+                    .is_ok()
+            {
+                return Slot(self);
+            }
+
+            // Limits can also change through responses to requests made outside the scheduler
+            let _ = tokio::time::timeout(self.slowdown, released).await;
+        }
+    }
+}
+
+/// A running job, releasing its slot when dropped (also if the job is cancelled)
+#[cfg(feature = "tokio")]
+struct Slot<'a>(&'a AdaptiveScheduler);
+
+#[cfg(feature = "tokio")]
+impl Drop for Slot<'_> {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::AcqRel);
+        self.0.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(RateLimitInfo::from_headers(&headers), None);
+
+        headers.insert("current-concurrent-requests", "4".parse().unwrap());
+        headers.insert("maximum-concurrent-requests", "5".parse().unwrap());
+        let info = RateLimitInfo::from_headers(&headers).unwrap();
+        assert_eq!(info.utilization(), 0.8);
+
+        #[cfg(feature = "tokio")]
+        {
+            let client = ElevenLabsTTVClient::new("test-key");
+            let scheduler = AdaptiveScheduler::new(&client, 10);
+            assert_eq!(scheduler.allowed_concurrency(), 10);
+
+            client.inner.rate_limits.update(&headers);
+            assert_eq!(scheduler.allowed_concurrency(), 4);
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_cooldown_is_shared_by_clones() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let clone = client.clone();
+        assert_eq!(clone.rate_limit_cooldown(), None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "30".parse().unwrap());
+        client.inner.rate_limits.rate_limited(&headers);
+        // A shorter Retry-After does not cut an active cooldown short
+        client.inner.rate_limits.rate_limited(&HeaderMap::new());
+
+        let remaining = clone.rate_limit_cooldown().unwrap();
+        assert!(remaining > Duration::from_secs(29) && remaining <= Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_rate_limit_cooldown_is_capped() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, u64::MAX.to_string().parse().unwrap());
+        client.inner.rate_limits.rate_limited(&headers);
+
+        let remaining = client.rate_limit_cooldown().unwrap();
+        assert!(remaining <= MAX_RATE_LIMIT_COOLDOWN);
+        assert!(remaining > MAX_RATE_LIMIT_COOLDOWN - Duration::from_secs(1));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_scheduler_bounds_concurrency() {
+        use std::sync::Arc;
+
+        let client = ElevenLabsTTVClient::new("test-key");
+        let scheduler = Arc::new(AdaptiveScheduler::new(&client, 2));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut jobs = tokio::task::JoinSet::new();
+        for _ in 0..6 {
+            let scheduler = scheduler.clone();
+            let peak = peak.clone();
+            jobs.spawn(async move {
+                scheduler
+                    .run(async {
+                        peak.fetch_max(scheduler.in_flight(), Ordering::AcqRel);
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    })
+                    .await
+            });
+        }
+        jobs.join_all().await;
+
+        assert_eq!(peak.load(Ordering::Acquire), 2);
+        assert_eq!(scheduler.in_flight(), 0);
+    }
+}
@@ -0,0 +1,156 @@
+use std::future::Future;
+
+use crate::{LanguageCode, TTVDesignVoiceResponseVoicePreview};
+
+/// Strategy used to pick one preview out of a Design Voice run
+///
+/// Implement this to plug a custom (e.g. human-in-the-loop) picker into higher-level workflows.
+/// Returns the index of the chosen preview, or `None` if no preview should be selected.
+pub trait PreviewSelector {
+    fn select(
+        &self,
+        previews: &[TTVDesignVoiceResponseVoicePreview],
+    ) -> impl Future<Output = Option<usize>> + Send;
+}
+
+/// Selects the first returned preview
+#[derive(Debug, Clone, Copy, Default)]
+pub struct First;
+
+impl PreviewSelector for First {
+    async fn select(&self, previews: &[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> {
+        if previews.is_empty() { None } else { Some(0) }
+    }
+}
+
+/// Selects the preview with the longest duration
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LongestDuration;
+
+impl PreviewSelector for LongestDuration {
+    async fn select(&self, previews: &[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> {
+        previews
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.duration_secs.total_cmp(&b.duration_secs))
+            .map(|(index, _)| index)
+    }
+}
+
+/// Selects the first preview in the given language, e.g. `LanguageCode::En` or "en"
+#[derive(Debug, Clone)]
+pub struct ByLanguage(pub LanguageCode);
+
+impl ByLanguage {
+    pub fn new<L: Into<LanguageCode>>(language: L) -> Self {
+        Self(language.into())
+    }
+}
+
+impl PreviewSelector for ByLanguage {
+    async fn select(&self, previews: &[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> {
+        previews.iter().position(|preview| {
+            preview
+                .language
+                .as_ref()
+                .is_some_and(|language| language.matches(&self.0))
+        })
+    }
+}
+
+/// Selector backed by a synchronous closure, see `from_fn`
+#[derive(Clone)]
+pub struct FnSelector<F>(F);
+
+impl<F> PreviewSelector for FnSelector<F>
+where
+    F: Fn(&[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> + Sync,
+{
+    async fn select(&self, previews: &[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> {
+        (self.0)(previews)
+    }
+}
+
+/// Selector backed by an async callback, see `from_async_fn`
+#[derive(Clone)]
+pub struct AsyncFnSelector<F>(F);
+
+impl<F, Fut> PreviewSelector for AsyncFnSelector<F>
+where
+    F: Fn(Vec<TTVDesignVoiceResponseVoicePreview>) -> Fut + Sync,
+    Fut: Future<Output = Option<usize>> + Send,
+{
+    async fn select(&self, previews: &[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> {
+        (self.0)(previews.to_vec()).await
+    }
+}
+
+/// Build a selector from a closure returning the index of the chosen preview
+pub fn from_fn<F>(f: F) -> FnSelector<F>
+where
+    F: Fn(&[TTVDesignVoiceResponseVoicePreview]) -> Option<usize> + Sync,
+{
+    FnSelector(f)
+}
+
+/// Build a selector from an async callback (e.g. one that waits for a user to pick in a UI).
+/// The callback receives an owned copy of the previews.
+pub fn from_async_fn<F, Fut>(f: F) -> AsyncFnSelector<F>
+where
+    F: Fn(Vec<TTVDesignVoiceResponseVoicePreview>) -> Fut + Sync,
+    Fut: Future<Output = Option<usize>> + Send,
+{
+    AsyncFnSelector(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview(
+        id: &str,
+        duration_secs: f64,
+        language: Option<&str>,
+    ) -> TTVDesignVoiceResponseVoicePreview {
+        TTVDesignVoiceResponseVoicePreview {
+            language: language.map(LanguageCode::from),
+            ..TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id(id)
+                .duration_secs(duration_secs)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builtin_selectors() {
+        let previews = vec![
+            preview("a", 3.0, Some("en")),
+            preview("b", 5.5, Some("de")),
+            preview("c", 4.0, None),
+            preview("d", 2.0, Some("pt-BR")),
+        ];
+
+        assert_eq!(First.select(&previews).await, Some(0));
+        assert_eq!(LongestDuration.select(&previews).await, Some(1));
+        assert_eq!(ByLanguage::new("DE").select(&previews).await, Some(1));
+        assert_eq!(ByLanguage::new("fr").select(&previews).await, None);
+        assert_eq!(ByLanguage::new("pt-br").select(&previews).await, Some(3));
+        assert_eq!(First.select(&[]).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_closure_selectors() {
+        let previews = vec![preview("a", 3.0, None), preview("b", 5.5, None)];
+
+        let by_id = from_fn(|previews: &[TTVDesignVoiceResponseVoicePreview]| {
+            previews.iter().position(|p| p.generated_voice_id == "b")
+        });
+        assert_eq!(by_id.select(&previews).await, Some(1));
+
+        let last = from_async_fn(
+            |previews: Vec<TTVDesignVoiceResponseVoicePreview>| async move {
+                previews.len().checked_sub(1)
+            },
+        );
+        assert_eq!(last.select(&previews).await, Some(1));
+    }
+}
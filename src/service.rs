@@ -0,0 +1,48 @@
+//! `tower::Service` implementations (`tower` feature)
+//!
+//! Lets the client be wrapped with standard tower layers (retry, timeout, rate limit,
+//! load shed, ...): `ServiceBuilder::new().timeout(..).service(client)`. Requests are sent
+//! as-is; build them with the builders' `build()` to apply defaults and validation.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tower::Service;
+
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, TTVCreateVoiceRequest, TTVCreateVoiceResponse,
+    TTVDesignVoiceRequest, TTVDesignVoiceResponse,
+};
+
+type ServiceFuture<T> = Pin<Box<dyn Future<Output = Result<T, ElevenLabsTTVError>> + Send>>;
+
+impl Service<TTVDesignVoiceRequest> for ElevenLabsTTVClient {
+    type Response = TTVDesignVoiceResponse;
+    type Error = ElevenLabsTTVError;
+    type Future = ServiceFuture<TTVDesignVoiceResponse>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: TTVDesignVoiceRequest) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { client.execute_design_voice(request).await })
+    }
+}
+
+impl Service<TTVCreateVoiceRequest> for ElevenLabsTTVClient {
+    type Response = TTVCreateVoiceResponse;
+    type Error = ElevenLabsTTVError;
+    type Future = ServiceFuture<TTVCreateVoiceResponse>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: TTVCreateVoiceRequest) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { client.execute_create_voice(request).await })
+    }
+}
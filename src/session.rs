@@ -0,0 +1,174 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AuditionTracker, ElevenLabsTTVClient, ElevenLabsTTVError, PreviewSelector,
+    TTVDesignVoiceRequest, TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview,
+    TextToVoiceCreateVoiceBuilder,
+};
+
+/// A Text-to-Voice design run that can be persisted and resumed later
+///
+/// Holds the description, the parameters used and every preview returned by the API
+/// (including their `generated_voice_id`s), so voice selection can be finished in a later
+/// process without regenerating previews.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesignSession {
+    /// The request used to generate the previews
+    pub request: TTVDesignVoiceRequest,
+    /// All previews returned for this session
+    pub previews: Vec<TTVDesignVoiceResponseVoicePreview>,
+    /// The text used to preview the voices
+    pub text: String,
+    /// Previews played and selected so far
+    #[serde(default)]
+    pub audition: AuditionTracker,
+}
+
+impl DesignSession {
+    /// Create a new session from a design request and its response
+    pub fn new(request: TTVDesignVoiceRequest, response: TTVDesignVoiceResponse) -> Self {
+        Self {
+            request,
+            previews: response.previews,
+            text: response.text,
+            audition: AuditionTracker::new(),
+        }
+    }
+
+    /// The voice description used for this session
+    pub fn voice_description(&self) -> &str {
+        &self.request.voice_description
+    }
+
+    /// All generated voice IDs in this session, in the order they were returned
+    pub fn generated_voice_ids(&self) -> Vec<&str> {
+        self.previews
+            .iter()
+            .map(|preview| preview.generated_voice_id.as_str())
+            .collect()
+    }
+
+    /// Find a preview by its generated voice ID
+    pub fn preview(&self, generated_voice_id: &str) -> Option<&TTVDesignVoiceResponseVoicePreview> {
+        self.previews
+            .iter()
+            .find(|preview| preview.generated_voice_id == generated_voice_id)
+    }
+
+    /// Pick a preview using the given selection strategy
+    pub async fn select<P: PreviewSelector>(
+        &self,
+        selector: &P,
+    ) -> Option<&TTVDesignVoiceResponseVoicePreview> {
+        let index = selector.select(&self.previews).await?;
+        self.previews.get(index)
+    }
+
+    /// Append the previews of another design run (e.g. a re-run with the same parameters)
+    pub fn extend(&mut self, response: TTVDesignVoiceResponse) {
+        self.previews.extend(response.previews);
+    }
+
+    /// Start building a Create Voice request for one of this session's previews,
+    /// reusing the session's voice description and its audition history.
+    pub fn create_voice<'a, S: Into<String>>(
+        &self,
+        client: &'a ElevenLabsTTVClient,
+        voice_name: S,
+        generated_voice_id: S,
+    ) -> TextToVoiceCreateVoiceBuilder<'a> {
+        client
+            .create_voice(
+                voice_name.into(),
+                self.request.voice_description.clone(),
+                generated_voice_id.into(),
+            )
+            .audition(&self.audition)
+    }
+
+    /// Save the session as JSON to the given path
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTVError> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load a session previously written with `save`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_session() -> DesignSession {
+        let request = TTVDesignVoiceRequest {
+            voice_description: "Calm, deep male narrator, 50s, British accent".to_string(),
+            output_format: None,
+            model_id: Some("eleven_multilingual_ttv_v2".to_string()),
+            text: None,
+            auto_generate_text: Some(true),
+            loudness: Some(0.5),
+            seed: Some(42),
+            guidance_scale: Some(5),
+            stream_previews: Some(false),
+            remixing_session_id: None,
+            remixing_session_iteration_id: None,
+            quality: None,
+            reference_audio_base64: None,
+            reference_audios: Vec::new(),
+            prompt_strength: None,
+            extra_body: serde_json::Map::new(),
+            extra_query: Vec::new(),
+        };
+        let response = TTVDesignVoiceResponse::fixture().previews(vec![
+            TTVDesignVoiceResponseVoicePreview::fixture()
+                .generated_voice_id("gen-1")
+                .audio_base_64("AAAA"),
+        ]);
+
+        DesignSession::new(request, response)
+    }
+
+    #[test]
+    fn test_session_save_and_load() {
+        let session = sample_session();
+        let path = std::env::temp_dir().join(format!(
+            "elevenlabs_ttv_session_test-{}.json",
+            std::process::id()
+        ));
+
+        session.save(&path).unwrap();
+        let loaded = DesignSession::load(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.voice_description(), session.voice_description());
+        assert_eq!(loaded.generated_voice_ids(), vec!["gen-1"]);
+        assert_eq!(loaded.request.seed, Some(42));
+        assert_eq!(loaded.preview("gen-1").unwrap().audio_base_64, "AAAA");
+    }
+
+    #[test]
+    fn test_session_create_voice_reuses_description() {
+        let session = sample_session();
+        let client = ElevenLabsTTVClient::new("test-key");
+        let builder = session.create_voice(&client, "Arthur", "gen-1");
+
+        assert_eq!(builder.voice_description, session.request.voice_description);
+        assert_eq!(builder.generated_voice_id, "gen-1".to_string());
+    }
+
+    #[tokio::test]
+    async fn test_session_select() {
+        let session = sample_session();
+        let selected = session.select(&crate::selector::First).await.unwrap();
+
+        assert_eq!(selected.generated_voice_id, "gen-1");
+    }
+}
@@ -0,0 +1,78 @@
+use reqwest::multipart::{Form, Part};
+
+use crate::voice_clone::audio_mime_type;
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, SimilarVoicesResponse};
+
+/// Builder for Find Similar Voices requests (POST /v1/similar-voices)
+pub struct SimilarVoicesBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
+    audio: Vec<u8>,
+    pub similarity_threshold: Option<f32>,
+    pub top_k: Option<u32>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start building a request for the Voice Library voices most similar to a reference clip
+    ///
+    /// Together with `design_voice`, this lets a caller first look for an existing voice and
+    /// only design a new one when nothing close enough is found.
+    pub fn find_similar_voices<A: Into<Vec<u8>>>(
+        &self,
+        audio_bytes: A,
+    ) -> SimilarVoicesBuilder<'_> {
+        SimilarVoicesBuilder {
+            client: self,
+            audio: audio_bytes.into(),
+            similarity_threshold: None,
+            top_k: None,
+        }
+    }
+}
+
+impl<'a> SimilarVoicesBuilder<'a> {
+    /// Maximum distance of the returned voices, from 0 (identical) to 2. Lower is stricter
+    pub fn similarity_threshold(mut self, similarity_threshold: f32) -> Self {
+        self.similarity_threshold = Some(similarity_threshold);
+        self
+    }
+
+    /// Number of voices to return, from 1 to 100
+    pub fn top_k(mut self, top_k: u32) -> Self {
+        self.top_k = Some(top_k);
+        self
+    }
+
+    /// Execute the Find Similar Voices request
+    pub async fn execute(self) -> Result<SimilarVoicesResponse, ElevenLabsTTVError> {
+        if self.audio.is_empty() {
+            return Err(ElevenLabsTTVError::ValidationError(
+                "A reference clip is required to find similar voices".to_string(),
+            ));
+        }
+
+        let file_name = format!(
+            "reference.{}",
+            crate::reference_audio::detect_format(&self.audio).unwrap_or("bin")
+        );
+        let audio = Part::bytes(self.audio)
+            .mime_str(audio_mime_type(&file_name))?
+            .file_name(file_name);
+        let mut form = Form::new().part("audio_file", audio);
+
+        if let Some(similarity_threshold) = self.similarity_threshold {
+            form = form.text("similarity_threshold", similarity_threshold.to_string());
+        }
+        if let Some(top_k) = self.top_k {
+            form = form.text("top_k", top_k.to_string());
+        }
+
+        let url = self.client.url(&["similar-voices"])?;
+
+        self.client
+            .send_json(
+                "similar-voices",
+                self.client.inner.client.post(url).multipart(form),
+            )
+            .await
+    }
+}
@@ -0,0 +1,90 @@
+//! Writing audio into any `AsyncWrite` sink (files, S3 multipart uploads, HTTP responses, ...)
+//! as it is received, without buffering it in memory or going through temporary files
+
+use reqwest::RequestBuilder;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, TTVDesignVoiceResponseVoicePreview};
+
+impl ElevenLabsTTVClient {
+    /// `stream_preview`, writing the audio into `sink` as it is downloaded.
+    /// Returns the number of bytes written.
+    pub async fn stream_preview_to<W: AsyncWrite + Unpin>(
+        &self,
+        generated_voice_id: &str,
+        sink: &mut W,
+    ) -> Result<u64, ElevenLabsTTVError> {
+        let url = self.url(&["text-to-voice", generated_voice_id, "stream"])?;
+
+        self.send_audio_to(
+            "text-to-voice/{generated_voice_id}/stream",
+            self.inner.client.get(url),
+            sink,
+        )
+        .await
+    }
+
+    /// `speak`, writing the audio into `sink` as it is received.
+    /// Returns the number of bytes written.
+    pub async fn speak_to<W: AsyncWrite + Unpin>(
+        &self,
+        voice_id: &str,
+        text: &str,
+        sink: &mut W,
+    ) -> Result<u64, ElevenLabsTTVError> {
+        self.send_audio_to(
+            "text-to-speech/{voice_id}",
+            self.speak_request(voice_id, text)?,
+            sink,
+        )
+        .await
+    }
+
+    /// Send a request (see `send`) and copy the audio response body into `sink` chunk by chunk
+    async fn send_audio_to<W: AsyncWrite + Unpin>(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+        sink: &mut W,
+    ) -> Result<u64, ElevenLabsTTVError> {
+        let mut response = self.send(endpoint, request).await?;
+
+        let mut written = 0;
+        while let Some(chunk) = response.chunk().await? {
+            sink.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        sink.flush().await?;
+        self.record_audio_bytes(endpoint, written);
+
+        Ok(written)
+    }
+}
+
+impl TTVDesignVoiceResponseVoicePreview {
+    /// Decode the inline preview audio into `sink`. Returns the number of bytes written.
+    pub async fn write_audio_to<W: AsyncWrite + Unpin>(
+        &self,
+        sink: &mut W,
+    ) -> Result<u64, ElevenLabsTTVError> {
+        let audio = self.decode_audio()?;
+        sink.write_all(&audio).await?;
+        sink.flush().await?;
+
+        Ok(audio.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_audio_to() {
+        let preview = TTVDesignVoiceResponseVoicePreview::fixture().audio_base_64("aGVsbG8=");
+
+        let mut sink = Vec::new();
+        assert_eq!(preview.write_audio_to(&mut sink).await.unwrap(), 5);
+        assert_eq!(sink, b"hello");
+    }
+}
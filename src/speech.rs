@@ -0,0 +1,81 @@
+use bytes::Bytes;
+use reqwest::RequestBuilder;
+use serde::Serialize;
+
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, models};
+
+#[derive(Serialize)]
+struct SpeakRequest<'a> {
+    text: &'a str,
+    model_id: &'a str,
+}
+
+impl ElevenLabsTTVClient {
+    /// Render a line of text with a voice, e.g. to smoke-test a freshly created voice
+    ///
+    /// Wraps POST /v1/text-to-speech/:voice_id with the eleven_multilingual_v2 model and
+    /// returns the raw audio bytes (mp3_44100_128).
+    pub async fn speak(&self, voice_id: &str, text: &str) -> Result<Bytes, ElevenLabsTTVError> {
+        let endpoint = "text-to-speech/{voice_id}";
+        let response = self
+            .send(endpoint, self.speak_request(voice_id, text)?)
+            .await?;
+        let audio = response.bytes().await?;
+        self.record_audio_bytes(endpoint, audio.len() as u64);
+
+        Ok(audio)
+    }
+
+    /// Internal method to build the HTTP request for `speak`
+    pub(crate) fn speak_request(
+        &self,
+        voice_id: &str,
+        text: &str,
+    ) -> Result<RequestBuilder, ElevenLabsTTVError> {
+        let url = self.url(&["text-to-speech", voice_id])?;
+
+        Ok(self
+            .inner
+            .client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "audio/mpeg")
+            .json(&SpeakRequest {
+                text,
+                model_id: models::elevanlabs_models::ELEVEN_MULTILINGUAL_V2,
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_speak() {
+        use wiremock::matchers::{body_json, header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice-1"))
+            .and(header("Accept", "audio/mpeg"))
+            .and(body_json(serde_json::json!({
+                "text": "Testing, one two three",
+                "model_id": "eleven_multilingual_v2"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"audio".as_slice()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let audio = client
+            .speak("voice-1", "Testing, one two three")
+            .await
+            .unwrap();
+
+        assert_eq!(audio, "audio");
+    }
+}
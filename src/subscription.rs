@@ -0,0 +1,157 @@
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, ParameterViolation, Subscription,
+    TTVDesignVoiceRequest,
+};
+
+/// Subscription tiers, from lowest to highest
+const TIERS: [&str; 7] = [
+    "free",
+    "starter",
+    "creator",
+    "pro",
+    "scale",
+    "business",
+    "enterprise",
+];
+
+/// Lowest tier offering an output format, for formats not available on every tier
+fn minimum_tier(output_format: &str) -> Option<&'static str> {
+    match output_format {
+        "mp3_44100_192" => Some("creator"),
+        "pcm_44100" | "pcm_48000" => Some("pro"),
+        _ => None,
+    }
+}
+
+/// Position of a tier in `TIERS`, ignoring suffixes like "_2024". `None` for unknown tiers.
+fn tier_rank(tier: &str) -> Option<usize> {
+    let tier = tier.to_ascii_lowercase();
+    TIERS
+        .iter()
+        .rposition(|known| tier.starts_with(known) || tier.ends_with(known))
+}
+
+impl Subscription {
+    /// Whether the tier offers `output_format`, e.g. `pcm_44100` requires Pro or above.
+    /// Unknown tiers are assumed to offer every format.
+    pub fn supports_output_format(&self, output_format: &str) -> bool {
+        match (minimum_tier(output_format), tier_rank(&self.tier)) {
+            (Some(minimum), Some(rank)) => {
+                tier_rank(minimum).is_some_and(|minimum| rank >= minimum)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl TTVDesignVoiceRequest {
+    /// `violations`, plus an output format the subscription's tier does not offer
+    ///
+    /// Log them as warnings, or use `validate_for` to fail locally with an actionable error
+    /// instead of a confusing API rejection.
+    pub fn violations_for(&self, subscription: &Subscription) -> Vec<ParameterViolation> {
+        let mut violations = self.violations();
+        if let Some(output_format) = &self.output_format
+            && !subscription.supports_output_format(output_format)
+        {
+            violations.push(ParameterViolation {
+                field: "output_format",
+                value: output_format.clone(),
+                allowed: format!(
+                    "formats of the {} tier; {} needs the {} tier or above",
+                    subscription.tier,
+                    output_format,
+                    minimum_tier(output_format).unwrap_or_default()
+                ),
+            });
+        }
+        violations
+    }
+
+    /// Check the request parameters, including the output format against the subscription's tier
+    pub fn validate_for(&self, subscription: &Subscription) -> Result<(), ElevenLabsTTVError> {
+        crate::validation::into_result(self.violations_for(subscription))
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// Get the subscription of the account, including its character quota
+    /// (GET /v1/user/subscription)
+    pub async fn get_subscription(&self) -> Result<Subscription, ElevenLabsTTVError> {
+        let url = self.url(&["user", "subscription"])?;
+
+        self.send_json("user/subscription", self.inner.client.get(url))
+            .await
+    }
+
+    /// Check that at least `estimated_characters` characters (credits) are left before starting
+    /// a run, e.g. a batch of 50 voice designs, so it fails fast instead of halfway through
+    ///
+    /// Returns the subscription, or an `InsufficientQuotaError` when the remaining quota is
+    /// too low.
+    pub async fn ensure_quota(
+        &self,
+        estimated_characters: u64,
+    ) -> Result<Subscription, ElevenLabsTTVError> {
+        let subscription = self.get_subscription().await?;
+
+        let remaining = subscription.remaining_characters();
+        if remaining < estimated_characters {
+            return Err(ElevenLabsTTVError::InsufficientQuotaError {
+                required: estimated_characters,
+                remaining,
+            });
+        }
+        Ok(subscription)
+    }
+
+    /// Check a request against the account's subscription before sending it, see
+    /// `TTVDesignVoiceRequest::validate_for`
+    pub async fn validate_for_subscription(
+        &self,
+        request: &TTVDesignVoiceRequest,
+    ) -> Result<Subscription, ElevenLabsTTVError> {
+        let subscription = self.get_subscription().await?;
+
+        request.validate_for(&subscription)?;
+        Ok(subscription)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(tier: &str) -> Subscription {
+        serde_json::from_value(serde_json::json!({
+            "tier": tier,
+            "character_count": 0,
+            "character_limit": 10000
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_output_format_tiers() {
+        assert!(!subscription("starter").supports_output_format("mp3_44100_192"));
+        assert!(subscription("creator").supports_output_format("mp3_44100_192"));
+        assert!(!subscription("creator").supports_output_format("pcm_44100"));
+        assert!(subscription("enterprise").supports_output_format("pcm_44100"));
+        assert!(subscription("free").supports_output_format("mp3_44100_128"));
+        assert!(subscription("custom").supports_output_format("pcm_44100"));
+
+        let client = ElevenLabsTTVClient::new("test-key");
+        let request = client
+            .design_voice("Calm, deep-voiced narrator")
+            .output_format("pcm_44100")
+            .build()
+            .unwrap();
+        let violations = request.violations_for(&subscription("creator"));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].to_string(),
+            "output_format = pcm_44100 (allowed: formats of the creator tier; pcm_44100 needs \
+             the pro tier or above)"
+        );
+    }
+}
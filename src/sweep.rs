@@ -0,0 +1,59 @@
+use futures_util::future::join_all;
+
+use crate::{ElevenLabsTTVError, TTVDesignVoiceResponse, TextToVoiceDesignVoiceBuilder};
+
+/// Outcome of one design in a `guidance_scale` sweep
+#[derive(Debug)]
+pub struct GuidanceScaleResult {
+    pub guidance_scale: u32,
+    pub result: Result<TTVDesignVoiceResponse, ElevenLabsTTVError>,
+}
+
+impl<'a> TextToVoiceDesignVoiceBuilder<'a> {
+    /// Run the design once per `guidance_scale` value, concurrently, to find the sweet spot
+    /// between natural-sounding and on-prompt voices. Results are labeled and in the order
+    /// of `guidance_scales`; a failed design does not fail the others.
+    ///
+    /// Set a `.seed(...)` so previews differ only by their guidance scale.
+    /// Fails without sending anything if the builder or any value is invalid.
+    pub async fn sweep_guidance_scale<I: IntoIterator<Item = u32>>(
+        self,
+        guidance_scales: I,
+    ) -> Result<Vec<GuidanceScaleResult>, ElevenLabsTTVError> {
+        let (client, request) = self.into_parts()?;
+
+        let requests = guidance_scales
+            .into_iter()
+            .map(|guidance_scale| {
+                let mut request = request.clone();
+                request.guidance_scale = Some(guidance_scale);
+                request.validate()?;
+                Ok(request)
+            })
+            .collect::<Result<Vec<_>, ElevenLabsTTVError>>()?;
+
+        Ok(join_all(requests.into_iter().map(|request| async move {
+            GuidanceScaleResult {
+                guidance_scale: request.guidance_scale.unwrap_or_default(),
+                result: client.execute_design_voice(request).await,
+            }
+        }))
+        .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ElevenLabsTTVClient;
+
+    #[tokio::test]
+    async fn test_sweep_rejects_invalid_values() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let result = client
+            .design_voice("Calm, deep-voiced narrator")
+            .sweep_guidance_scale([5, 150])
+            .await;
+
+        assert!(result.is_err());
+    }
+}
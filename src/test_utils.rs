@@ -0,0 +1,356 @@
+//! Fixtures and a mock API server for testing code built on this crate (`test-utils` feature)
+//!
+//! ```no_run
+//! # async fn run() {
+//! use elevenlabs_ttv::test_utils::MockTTVServer;
+//!
+//! let server = MockTTVServer::start().await;
+//! let response = server.client().design_voice("Calm, deep-voiced narrator").execute().await.unwrap();
+//! assert_eq!(response.previews.len(), 3);
+//! # }
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use wiremock::matchers::{method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, LanguageCode, MediaType, TTVCreateVoiceResponse,
+    TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview, VoiceCategory,
+};
+
+/// Base64 of a tiny MP3 (ID3 header only) used as fixture preview audio
+pub const FIXTURE_AUDIO_BASE64: &str = "SUQzBAAAAAAAAA==";
+
+/// Generated voice IDs of the previews in `design_response_json`
+pub const FIXTURE_GENERATED_VOICE_IDS: [&str; 3] = ["gen-voice-1", "gen-voice-2", "gen-voice-3"];
+
+/// Voice ID of the voice in `create_response_json`
+pub const FIXTURE_VOICE_ID: &str = "voice-1";
+
+/// Sample Design Voice response in the shape returned by the API, with three previews
+pub const SAMPLE_DESIGN_RESPONSE: &str = include_str!("../fixtures/design_response.json");
+
+/// Sample Create Voice (and Get Voice) response in the shape returned by the API
+pub const SAMPLE_CREATE_RESPONSE: &str = include_str!("../fixtures/create_response.json");
+
+/// Sample List Voices response in the shape returned by the API, with a premade and a
+/// generated voice
+pub const SAMPLE_LIST_VOICES_RESPONSE: &str = include_str!("../fixtures/list_voices_response.json");
+
+/// Deserialize a response (or any other type) saved as JSON, e.g. a body captured from the API
+///
+/// ```no_run
+/// use elevenlabs_ttv::TTVDesignVoiceResponse;
+/// use elevenlabs_ttv::test_utils::from_json_file;
+///
+/// let response: TTVDesignVoiceResponse = from_json_file("tests/fixtures/design.json").unwrap();
+/// ```
+pub fn from_json_file<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+) -> Result<T, ElevenLabsTTVError> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Canned Design Voice response with three previews
+pub fn design_response_json() -> Value {
+    json!({
+        "previews": FIXTURE_GENERATED_VOICE_IDS.iter().map(|id| json!({
+            "audio_base_64": FIXTURE_AUDIO_BASE64,
+            "generated_voice_id": id,
+            "media_type": "audio/mpeg",
+            "duration_secs": 4.2,
+            "language": "en"
+        })).collect::<Vec<_>>(),
+        "text": "Fixture preview text used to audition the generated voices."
+    })
+}
+
+/// Canned Create Voice response
+pub fn create_response_json() -> Value {
+    json!({
+        "voice_id": FIXTURE_VOICE_ID,
+        "name": "Fixture Voice",
+        "category": "generated",
+        "description": "Fixture voice description",
+        "labels": {},
+        "created_at_unix": 1714204800
+    })
+}
+
+impl TTVDesignVoiceResponse {
+    /// The `design_response_json` fixture: three English MP3 previews of `FIXTURE_AUDIO_BASE64`
+    ///
+    /// Override parts of it builder-style:
+    ///
+    /// ```
+    /// use elevenlabs_ttv::{LanguageCode, TTVDesignVoiceResponse, TTVDesignVoiceResponseVoicePreview};
+    ///
+    /// let response = TTVDesignVoiceResponse::fixture()
+    ///     .text("Welcome aboard!")
+    ///     .previews(vec![TTVDesignVoiceResponseVoicePreview::fixture().language("fr")]);
+    /// assert_eq!(response.previews[0].language, Some(LanguageCode::Fr));
+    /// ```
+    pub fn fixture() -> Self {
+        serde_json::from_value(design_response_json()).expect("valid design fixture")
+    }
+
+    /// Load a Design Voice response saved as JSON, see `from_json_file`
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        from_json_file(path)
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn previews(mut self, previews: Vec<TTVDesignVoiceResponseVoicePreview>) -> Self {
+        self.previews = previews;
+        self
+    }
+
+    pub fn add_preview(mut self, preview: TTVDesignVoiceResponseVoicePreview) -> Self {
+        self.previews.push(preview);
+        self
+    }
+}
+
+impl TTVDesignVoiceResponseVoicePreview {
+    /// The first preview of `TTVDesignVoiceResponse::fixture()`
+    pub fn fixture() -> Self {
+        TTVDesignVoiceResponse::fixture().previews.remove(0)
+    }
+
+    pub fn generated_voice_id(mut self, generated_voice_id: impl Into<String>) -> Self {
+        self.generated_voice_id = generated_voice_id.into();
+        self
+    }
+
+    pub fn audio_base_64(mut self, audio_base_64: impl Into<String>) -> Self {
+        self.audio_base_64 = audio_base_64.into();
+        self
+    }
+
+    pub fn media_type(mut self, media_type: impl Into<MediaType>) -> Self {
+        self.media_type = media_type.into();
+        self
+    }
+
+    pub fn duration_secs(mut self, duration_secs: f64) -> Self {
+        self.duration_secs = duration_secs;
+        self
+    }
+
+    pub fn language(mut self, language: impl Into<LanguageCode>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+}
+
+impl TTVCreateVoiceResponse {
+    /// The `create_response_json` fixture: a generated voice with ID `FIXTURE_VOICE_ID`
+    pub fn fixture() -> Self {
+        serde_json::from_value(create_response_json()).expect("valid create fixture")
+    }
+
+    /// Load a Create Voice (or Get Voice) response saved as JSON, see `from_json_file`
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        from_json_file(path)
+    }
+
+    pub fn voice_id(mut self, voice_id: impl Into<String>) -> Self {
+        self.voice_id = voice_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn category(mut self, category: VoiceCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels
+            .get_or_insert_default()
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// wiremock priority of the default routes: the lowest, so routes mounted by tests (priority
+/// 5 unless set) take precedence
+const DEFAULT_ROUTE_PRIORITY: u8 = u8::MAX;
+
+/// A wiremock server preloaded with the Text-to-Voice routes, answering with the fixtures
+pub struct MockTTVServer {
+    server: MockServer,
+}
+
+impl MockTTVServer {
+    /// Start the server with the default routes:
+    /// design, create, preview streaming, remix, list voices and get voice
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(design_response_json()))
+            .with_priority(DEFAULT_ROUTE_PRIORITY)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(create_response_json()))
+            .with_priority(DEFAULT_ROUTE_PRIORITY)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/v1/text-to-voice/[^/]+/stream$"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "audio/mpeg")
+                    .set_body_bytes(b"ID3\x04\x00\x00\x00\x00\x00\x00".as_slice()),
+            )
+            .with_priority(DEFAULT_ROUTE_PRIORITY)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/v1/text-to-voice/[^/]+/remix$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(design_response_json()))
+            .with_priority(DEFAULT_ROUTE_PRIORITY)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "voices": [create_response_json()] })),
+            )
+            .with_priority(DEFAULT_ROUTE_PRIORITY)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/v1/voices/[^/]+$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(create_response_json()))
+            .with_priority(DEFAULT_ROUTE_PRIORITY)
+            .mount(&server)
+            .await;
+
+        Self { server }
+    }
+
+    /// Base URL to pass to `ElevenLabsTTVClient::with_base_url` or the client builder
+    pub fn base_url(&self) -> String {
+        format!("{}/v1", self.server.uri())
+    }
+
+    /// A client pointed at this server
+    pub fn client(&self) -> ElevenLabsTTVClient {
+        ElevenLabsTTVClient::with_base_url("test-key".to_string(), self.base_url())
+    }
+
+    /// The underlying wiremock server, e.g. to mount additional routes. Routes mounted with
+    /// the default priority take precedence over the default ones
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_fixtures() {
+        let design = TTVDesignVoiceResponse::fixture()
+            .add_preview(TTVDesignVoiceResponseVoicePreview::fixture().generated_voice_id("gen-4"));
+        assert_eq!(design.previews.len(), 4);
+        assert_eq!(design.previews[3].generated_voice_id, "gen-4");
+        assert_eq!(design.previews[3].audio_base_64, FIXTURE_AUDIO_BASE64);
+
+        let voice = TTVCreateVoiceResponse::fixture()
+            .name("Narrator")
+            .label("accent", "irish");
+        assert_eq!(voice.voice_id, FIXTURE_VOICE_ID);
+        assert_eq!(voice.name.as_deref(), Some("Narrator"));
+        assert_eq!(voice.labels.unwrap()["accent"], "irish");
+    }
+
+    #[test]
+    fn test_bundled_samples_deserialize() {
+        let design: TTVDesignVoiceResponse = serde_json::from_str(SAMPLE_DESIGN_RESPONSE).unwrap();
+        assert_eq!(design.previews.len(), 3);
+        let voices: crate::ListVoicesResponse =
+            serde_json::from_str(SAMPLE_LIST_VOICES_RESPONSE).unwrap();
+        assert_eq!(voices.voices.len(), 2);
+
+        let path = std::env::temp_dir().join(format!("ttv-sample-{}.json", std::process::id()));
+        fs::write(&path, SAMPLE_CREATE_RESPONSE).unwrap();
+        let voice = TTVCreateVoiceResponse::from_json_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(voice.unwrap().name.as_deref(), Some("Lighthouse Keeper"));
+    }
+
+    #[tokio::test]
+    async fn test_mock_server_routes() {
+        let server = MockTTVServer::start().await;
+        let client = server.client();
+
+        let design = client
+            .design_voice("Calm, deep-voiced narrator")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(design.previews.len(), 3);
+
+        let voice = client
+            .create_voice(
+                "Narrator",
+                "Calm, deep-voiced narrator",
+                FIXTURE_GENERATED_VOICE_IDS[0],
+            )
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(voice.voice_id, FIXTURE_VOICE_ID);
+
+        let audio = client.stream_preview("gen-voice-1").await.unwrap();
+        assert!(audio.starts_with(b"ID3"));
+    }
+
+    #[tokio::test]
+    async fn test_mounted_routes_override_the_defaults() {
+        let server = MockTTVServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "previews": [], "text": "Overridden" })),
+            )
+            .mount(server.server())
+            .await;
+
+        let design = server
+            .client()
+            .design_voice("Calm, deep-voiced narrator")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(design.text, "Overridden");
+        assert!(design.previews.is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+//! `DateTime<Utc>` accessors for the `*_unix` timestamps returned by the API (`chrono` feature)
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    FineTuning, HistoryItem, ManualVerification, ModerationCheck, Recording,
+    RemixingSessionIteration, TTVCreateVoiceResponse, VerificationAttempt, VerificationFile,
+    VoiceSharing,
+};
+
+/// Unix timestamp (seconds) as a `DateTime<Utc>`, `None` if out of range
+fn from_unix(secs: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(secs, 0)
+}
+
+impl TTVCreateVoiceResponse {
+    /// `created_at_unix` as a `DateTime<Utc>`
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_unix.and_then(from_unix)
+    }
+
+    /// `favorited_at_unix` as a `DateTime<Utc>`
+    pub fn favorited_at(&self) -> Option<DateTime<Utc>> {
+        self.favorited_at_unix.and_then(from_unix)
+    }
+}
+
+impl RemixingSessionIteration {
+    /// `created_at_unix` as a `DateTime<Utc>`
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_unix.and_then(from_unix)
+    }
+}
+
+impl HistoryItem {
+    /// `date_unix` as a `DateTime<Utc>`
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        self.date_unix.and_then(from_unix)
+    }
+}
+
+impl FineTuning {
+    /// `next_max_verification_attempts_reset_unix_ms` as a `DateTime<Utc>`
+    pub fn next_max_verification_attempts_reset(&self) -> Option<DateTime<Utc>> {
+        self.next_max_verification_attempts_reset_unix_ms
+            .and_then(DateTime::from_timestamp_millis)
+    }
+}
+
+impl VerificationAttempt {
+    /// `date_unix` as a `DateTime<Utc>`
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        from_unix(self.date_unix)
+    }
+}
+
+impl Recording {
+    /// `upload_date_unix` as a `DateTime<Utc>`
+    pub fn upload_date(&self) -> Option<DateTime<Utc>> {
+        from_unix(self.upload_date_unix)
+    }
+}
+
+impl ManualVerification {
+    /// `request_time_unix` as a `DateTime<Utc>`
+    pub fn request_time(&self) -> Option<DateTime<Utc>> {
+        from_unix(self.request_time_unix)
+    }
+}
+
+impl VerificationFile {
+    /// `upload_date_unix` as a `DateTime<Utc>`
+    pub fn upload_date(&self) -> Option<DateTime<Utc>> {
+        from_unix(self.upload_date_unix)
+    }
+}
+
+impl VoiceSharing {
+    /// `date_unix` as a `DateTime<Utc>`
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        self.date_unix.and_then(from_unix)
+    }
+
+    /// `disable_at_unix` as a `DateTime<Utc>`
+    pub fn disable_at(&self) -> Option<DateTime<Utc>> {
+        self.disable_at_unix.and_then(from_unix)
+    }
+}
+
+impl ModerationCheck {
+    /// `date_checked_unix` as a `DateTime<Utc>`
+    pub fn date_checked(&self) -> Option<DateTime<Utc>> {
+        self.date_checked_unix.and_then(from_unix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_item_date() {
+        let item: HistoryItem = serde_json::from_value(serde_json::json!({
+            "history_item_id": "item-1",
+            "date_unix": 1714204800
+        }))
+        .unwrap();
+
+        assert_eq!(
+            item.date().unwrap().to_rfc3339(),
+            "2024-04-27T08:00:00+00:00"
+        );
+    }
+}
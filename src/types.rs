@@ -1,9 +1,18 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 
+use crate::{ElevenLabsTTVError, LanguageCode, MediaType};
+
 /// Request body for Text-to-Voice: Design Voice API calls
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[serde(deny_unknown_fields)]
 pub struct TTVDesignVoiceRequest {
     pub voice_description: String,
     pub output_format: Option<String>,
@@ -18,20 +27,79 @@ pub struct TTVDesignVoiceRequest {
     pub remixing_session_iteration_id: Option<String>,
     pub quality: Option<f32>,
     pub reference_audio_base64: Option<String>,
+    /// Several weighted reference clips, for when the design endpoint accepts more than one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reference_audios: Vec<ReferenceAudio>,
     pub prompt_strength: Option<f32>,
+    /// Additional body parameters not (yet) modeled by this crate, merged into the request
+    /// body as-is. Kept under their own key here, so unknown keys fail to deserialize
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::arbitrary_json_map))]
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
+    /// Additional query parameters not (yet) modeled by this crate, sent as-is
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_query: Vec<(String, String)>,
+}
+
+/// A base64-encoded reference clip with its relative influence on the designed voice
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct ReferenceAudio {
+    pub audio_base64: String,
+    /// >=0 <=1, defaults to an equal share between clips when `None`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+}
+
+impl ReferenceAudio {
+    /// A reference clip from base64-encoded audio, without an explicit weight
+    pub fn new<S: Into<String>>(audio_base64: S) -> Self {
+        Self {
+            audio_base64: audio_base64.into(),
+            weight: None,
+        }
+    }
+
+    /// Relative influence of the clip, >=0 <=1
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
 }
 
 /// Request body for Text-to-Voice: Create Voice API calls
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+#[serde(deny_unknown_fields)]
 pub struct TTVCreateVoiceRequest {
     pub voice_name: String,
     pub voice_description: String,
     pub generated_voice_id: String,
     pub labels: Option<String>,
-    pub played_not_selected_voice_ids: Option<String>,
+    pub played_not_selected_voice_ids: Option<Vec<String>>,
+    /// Additional body parameters not (yet) modeled by this crate, merged into the request
+    /// body as-is. Kept under their own key here, so unknown keys fail to deserialize
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::arbitrary_json_map))]
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
+    /// Additional query parameters not (yet) modeled by this crate, sent as-is
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_query: Vec<(String, String)>,
+}
+
+/// Untyped response returned by `execute_raw`
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body as untyped JSON
+    pub body: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct TTVDesignVoiceResponse {
     /// List of voice previews
     pub previews: Vec<TTVDesignVoiceResponseVoicePreview>,
@@ -40,20 +108,22 @@ pub struct TTVDesignVoiceResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct TTVDesignVoiceResponseVoicePreview {
     /// The base64 encoded audio of the preview
     pub audio_base_64: String,
     /// The ID of the generated voice. Use it to create a voice from the preview
     pub generated_voice_id: String,
     /// The media type of the preview
-    pub media_type: String,
+    pub media_type: MediaType,
     /// The duration of the preview in seconds
     pub duration_secs: f64,
     /// The language of the preview (can be None)
-    pub language: Option<String>,
+    pub language: Option<LanguageCode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct TTVCreateVoiceResponse {
     pub voice_id: String,
     pub name: Option<String>,
@@ -80,7 +150,196 @@ pub struct TTVCreateVoiceResponse {
     pub created_at_unix: Option<i64>,
 }
 
+/// Generic status response returned by action endpoints, e.g. `{"status": "ok"}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct StatusResponse {
+    pub status: String,
+}
+
+/// Subscription of the account, from GET /v1/user/subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct Subscription {
+    pub tier: String,
+    /// Characters (credits) used in the current period
+    pub character_count: u64,
+    /// Characters (credits) available per period
+    pub character_limit: u64,
+    pub can_extend_character_limit: Option<bool>,
+    pub allowed_to_extend_character_limit: Option<bool>,
+    pub next_character_count_reset_unix: Option<i64>,
+    pub voice_slots_used: Option<u32>,
+    pub voice_limit: Option<u32>,
+    pub professional_voice_limit: Option<u32>,
+    pub can_use_instant_voice_cloning: Option<bool>,
+    pub can_use_professional_voice_cloning: Option<bool>,
+    pub currency: Option<String>,
+    pub status: Option<String>,
+    pub billing_period: Option<String>,
+    pub character_refresh_period: Option<String>,
+}
+
+impl Subscription {
+    /// Characters (credits) left in the current period
+    pub fn remaining_characters(&self) -> u64 {
+        self.character_limit.saturating_sub(self.character_count)
+    }
+}
+
+/// A model from GET /v1/models
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct Model {
+    pub model_id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub can_do_text_to_speech: Option<bool>,
+    pub can_do_voice_conversion: Option<bool>,
+    pub requires_alpha_access: Option<bool>,
+    pub token_cost_factor: Option<f64>,
+    #[serde(default)]
+    pub languages: Vec<ModelLanguage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct ModelLanguage {
+    pub language_id: LanguageCode,
+    pub name: Option<String>,
+}
+
+/// Response of the Instant Voice Clone endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct AddVoiceResponse {
+    pub voice_id: String,
+    #[serde(default)]
+    pub requires_verification: bool,
+}
+
+/// Response of endpoints creating a voice, e.g. Professional Voice Clone creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct VoiceIdResponse {
+    pub voice_id: String,
+}
+
+/// A voice in the account, as returned by the voices endpoints (same shape as a created voice)
+pub type Voice = TTVCreateVoiceResponse;
+
+/// Response of the List Voices endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct ListVoicesResponse {
+    pub voices: Vec<Voice>,
+}
+
+/// Response of the Find Similar Voices endpoint, best matches first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct SimilarVoicesResponse {
+    pub voices: Vec<LibraryVoice>,
+    #[serde(default)]
+    pub has_more: bool,
+    pub last_sort_id: Option<String>,
+}
+
+/// A shared voice of the Voice Library
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct LibraryVoice {
+    pub public_owner_id: String,
+    pub voice_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<VoiceCategory>,
+    pub accent: Option<String>,
+    pub gender: Option<String>,
+    pub age: Option<String>,
+    pub descriptive: Option<String>,
+    pub use_case: Option<String>,
+    pub language: Option<LanguageCode>,
+    pub locale: Option<String>,
+    pub preview_url: Option<String>,
+    pub cloned_by_count: Option<u64>,
+    pub free_users_allowed: Option<bool>,
+    pub date_unix: Option<i64>,
+}
+
+/// Response of the List Remixing Session Iterations endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct RemixingSessionIterationsResponse {
+    pub remixing_session_id: String,
+    /// Iterations of the session, oldest first
+    pub iterations: Vec<RemixingSessionIteration>,
+}
+
+/// One iteration (round of previews) in a remixing session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct RemixingSessionIteration {
+    pub remixing_session_iteration_id: String,
+    /// Generated voice IDs of the previews created in this iteration
+    #[serde(default)]
+    pub generated_voice_ids: Vec<String>,
+    /// The description (or remix prompt) used for this iteration
+    pub voice_description: Option<String>,
+    pub created_at_unix: Option<i64>,
+}
+
+/// Response of the History endpoint (one page)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct HistoryResponse {
+    pub history: Vec<HistoryItem>,
+    /// ID of the last item of this page, to fetch the next page
+    pub last_history_item_id: Option<String>,
+    /// Whether more pages are available
+    pub has_more: bool,
+    pub scanned_until: Option<i64>,
+}
+
+/// A generated item in the account history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub struct HistoryItem {
+    pub history_item_id: String,
+    pub request_id: Option<String>,
+    pub voice_id: Option<String>,
+    pub model_id: Option<String>,
+    pub voice_name: Option<String>,
+    pub voice_category: Option<VoiceCategory>,
+    pub text: Option<String>,
+    pub date_unix: Option<i64>,
+    pub character_count_change_from: Option<i64>,
+    pub character_count_change_to: Option<i64>,
+    pub content_type: Option<String>,
+    pub state: Option<String>,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::arbitrary_optional_json))]
+    pub settings: Option<serde_json::Value>,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::arbitrary_optional_json))]
+    pub feedback: Option<serde_json::Value>,
+    pub share_link_id: Option<String>,
+    pub source: Option<String>,
+}
+
+impl HistoryItem {
+    /// Characters billed for this item
+    pub fn character_count(&self) -> i64 {
+        match (
+            self.character_count_change_from,
+            self.character_count_change_to,
+        ) {
+            (Some(from), Some(to)) => to - from,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct Sample {
     pub sample_id: Option<String>,
     pub file_name: Option<String>,
@@ -97,6 +356,7 @@ pub struct Sample {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct SpeakerSeparation {
     pub voice_id: String,
     pub sample_id: String,
@@ -106,15 +366,21 @@ pub struct SpeakerSeparation {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum SeparationStatus {
     NotStarted,
     Pending,
     Completed,
     Failed,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct Speaker {
     pub speaker_id: String,
     pub duration_secs: f64,
@@ -122,13 +388,16 @@ pub struct Speaker {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct Utterance {
     pub start: f64,
     pub end: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum VoiceCategory {
     Generated,
     Cloned,
@@ -136,9 +405,13 @@ pub enum VoiceCategory {
     Professional,
     Famous,
     HighQuality,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct FineTuning {
     pub is_allowed_to_fine_tune: Option<bool>,
     pub state: Option<HashMap<String, FineTuningState>>,
@@ -154,11 +427,14 @@ pub struct FineTuning {
     pub manual_verification: Option<ManualVerification>,
     pub max_verification_attempts: Option<i64>,
     pub next_max_verification_attempts_reset_unix_ms: Option<i64>,
+    #[cfg_attr(feature = "fuzz", arbitrary(with = crate::fuzz::arbitrary_optional_json))]
     pub finetuning_state: Option<serde_json::Value>, // Using Value for "any" type
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum FineTuningState {
     NotStarted,
     Queued,
@@ -166,9 +442,13 @@ pub enum FineTuningState {
     FineTuned,
     Failed,
     Delayed,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct VerificationAttempt {
     pub text: String,
     pub date_unix: i64,
@@ -179,6 +459,7 @@ pub struct VerificationAttempt {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct Recording {
     pub recording_id: String,
     pub mime_type: String,
@@ -188,6 +469,7 @@ pub struct Recording {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct ManualVerification {
     pub extra_text: String,
     pub request_time_unix: i64,
@@ -195,6 +477,7 @@ pub struct ManualVerification {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct VerificationFile {
     pub file_id: String,
     pub file_name: String,
@@ -204,6 +487,7 @@ pub struct VerificationFile {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct VoiceSettings {
     pub stability: Option<f64>,
     pub use_speaker_boost: Option<bool>,
@@ -213,6 +497,7 @@ pub struct VoiceSettings {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct VoiceSharing {
     pub status: Option<SharingStatus>,
     pub history_item_sample_id: Option<String>,
@@ -250,25 +535,36 @@ pub struct VoiceSharing {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum SharingStatus {
     Enabled,
     Disabled,
     Copied,
     CopiedDisabled,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ReviewStatus {
     NotRequested,
     Pending,
     Declined,
     Allowed,
     AllowedWithChanges,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct ModerationCheck {
     pub date_checked_unix: Option<i64>,
     pub name_value: Option<String>,
@@ -282,21 +578,28 @@ pub struct ModerationCheck {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct ReaderRestriction {
     pub resource_type: ResourceType,
     pub resource_id: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ResourceType {
     Read,
     Collection,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct VerifiedLanguage {
-    pub language: String,
+    pub language: LanguageCode,
     pub model_id: String,
     pub accent: Option<String>,
     pub locale: Option<String>,
@@ -304,16 +607,22 @@ pub struct VerifiedLanguage {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum SafetyControl {
     None,
     Ban,
     Captcha,
     EnterpriseBan,
     EnterpriseCaptcha,
+    /// A value added by the API after this crate version was released
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct VoiceVerification {
     pub requires_verification: bool,
     pub is_verified: bool,
@@ -323,6 +632,52 @@ pub struct VoiceVerification {
     pub verification_attempts: Option<Vec<VerificationAttempt>>,
 }
 
+impl TTVDesignVoiceResponseVoicePreview {
+    /// Decode the base64 preview audio into raw audio bytes (in `media_type` format)
+    pub fn decode_audio(&self) -> Result<Bytes, ElevenLabsTTVError> {
+        STANDARD
+            .decode(&self.audio_base_64)
+            .map(Bytes::from)
+            .map_err(|e| ElevenLabsTTVError::AudioDecodeError(e.to_string()))
+    }
+
+    /// `duration_secs` as a `Duration`
+    pub fn duration(&self) -> Duration {
+        secs_to_duration(self.duration_secs)
+    }
+}
+
+impl Sample {
+    /// `duration_secs` as a `Duration`
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration_secs.map(secs_to_duration)
+    }
+}
+
+impl Speaker {
+    /// `duration_secs` as a `Duration`
+    pub fn duration(&self) -> Duration {
+        secs_to_duration(self.duration_secs)
+    }
+}
+
+impl Utterance {
+    /// Start offset in the sample
+    pub fn start_time(&self) -> Duration {
+        secs_to_duration(self.start)
+    }
+
+    /// End offset in the sample
+    pub fn end_time(&self) -> Duration {
+        secs_to_duration(self.end)
+    }
+}
+
+/// Seconds reported by the API as a `Duration`, treating negative or invalid values as zero
+pub(crate) fn secs_to_duration(secs: f64) -> Duration {
+    Duration::try_from_secs_f64(secs).unwrap_or(Duration::ZERO)
+}
+
 // Example usage and helper implementations
 impl TTVCreateVoiceResponse {
     /// Check if the voice is ready for use
@@ -342,6 +697,11 @@ impl TTVCreateVoiceResponse {
             .unwrap_or(0.0)
     }
 
+    /// `total_sample_duration` as a `Duration`
+    pub fn total_sample_duration_time(&self) -> Duration {
+        secs_to_duration(self.total_sample_duration())
+    }
+
     /// Check if voice sharing is enabled
     pub fn is_shared(&self) -> bool {
         self.sharing
@@ -0,0 +1,219 @@
+//! UniFFI bindings (`uniffi` feature), so Kotlin and Swift apps can design, audition and
+//! create voices through this crate instead of reimplementing the API layer
+//!
+//! Generate the bindings from the built library with `uniffi-bindgen generate --library`.
+//! Errors surface as `ElevenLabsTTVError`, one case per variant with its message.
+
+use std::sync::{Arc, Mutex};
+
+use crate::{AuditionTracker, ElevenLabsTTVClient, ElevenLabsTTVError, TTVDesignVoiceResponse};
+
+/// Client handle for the bindings, see `ElevenLabsTTVClient`
+#[derive(uniffi::Object)]
+pub struct TTVClient {
+    client: ElevenLabsTTVClient,
+}
+
+/// Options of a Design Voice request. Unset options use the builder defaults
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TTVDesignOptions {
+    pub voice_description: String,
+    #[uniffi(default = None)]
+    pub text: Option<String>,
+    #[uniffi(default = None)]
+    pub model_id: Option<String>,
+    #[uniffi(default = None)]
+    pub output_format: Option<String>,
+    #[uniffi(default = None)]
+    pub seed: Option<u32>,
+    #[uniffi(default = None)]
+    pub guidance_scale: Option<u32>,
+    #[uniffi(default = None)]
+    pub loudness: Option<f32>,
+}
+
+/// A preview with its audio decoded
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TTVPreview {
+    pub generated_voice_id: String,
+    pub audio: Vec<u8>,
+    pub media_type: String,
+    pub duration_secs: f64,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TTVDesignResult {
+    pub previews: Vec<TTVPreview>,
+    /// The text used to preview the voices
+    pub text: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TTVCreatedVoice {
+    pub voice_id: String,
+    pub name: Option<String>,
+    pub preview_url: Option<String>,
+}
+
+/// Played and chosen previews, see `AuditionTracker`
+#[derive(Default, uniffi::Object)]
+pub struct TTVAudition {
+    tracker: Mutex<AuditionTracker>,
+}
+
+impl TryFrom<TTVDesignVoiceResponse> for TTVDesignResult {
+    type Error = ElevenLabsTTVError;
+
+    fn try_from(response: TTVDesignVoiceResponse) -> Result<Self, Self::Error> {
+        let previews = response
+            .previews
+            .into_iter()
+            .map(|preview| {
+                Ok(TTVPreview {
+                    audio: preview.decode_audio()?.to_vec(),
+                    generated_voice_id: preview.generated_voice_id,
+                    media_type: preview.media_type.to_string(),
+                    duration_secs: preview.duration_secs,
+                    language: preview.language.map(|language| language.to_string()),
+                })
+            })
+            .collect::<Result<_, ElevenLabsTTVError>>()?;
+
+        Ok(Self {
+            previews,
+            text: response.text,
+        })
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl TTVClient {
+    #[uniffi::constructor]
+    pub fn new(api_key: String) -> Arc<Self> {
+        Arc::new(Self {
+            client: ElevenLabsTTVClient::new(api_key),
+        })
+    }
+
+    /// Design voices, returning the previews with their audio decoded
+    pub async fn design_voice(
+        &self,
+        options: TTVDesignOptions,
+    ) -> Result<TTVDesignResult, ElevenLabsTTVError> {
+        let mut builder = self.client.design_voice(options.voice_description);
+        if let Some(text) = options.text {
+            builder = builder.text(text);
+        }
+        if let Some(model_id) = options.model_id {
+            builder = builder.model(model_id);
+        }
+        if let Some(output_format) = options.output_format {
+            builder = builder.output_format(output_format);
+        }
+        if let Some(seed) = options.seed {
+            builder = builder.seed(seed);
+        }
+        if let Some(guidance_scale) = options.guidance_scale {
+            builder = builder.guidance_scale(guidance_scale);
+        }
+        if let Some(loudness) = options.loudness {
+            builder = builder.loudness(loudness);
+        }
+
+        builder.execute().await?.try_into()
+    }
+
+    /// Download the audio of a preview generated with `stream_previews`
+    pub async fn stream_preview(
+        &self,
+        generated_voice_id: String,
+    ) -> Result<Vec<u8>, ElevenLabsTTVError> {
+        Ok(self
+            .client
+            .stream_preview(&generated_voice_id)
+            .await?
+            .to_vec())
+    }
+
+    /// Create a voice from the preview selected in `audition`, reporting the other played
+    /// previews as feedback
+    pub async fn create_voice(
+        &self,
+        voice_name: String,
+        voice_description: String,
+        audition: Arc<TTVAudition>,
+    ) -> Result<TTVCreatedVoice, ElevenLabsTTVError> {
+        let builder = audition
+            .tracker
+            .lock()
+            .unwrap()
+            .create_voice(&self.client, voice_name, voice_description)
+            .ok_or_else(|| {
+                ElevenLabsTTVError::ValidationError("No preview has been selected".to_string())
+            })?;
+        let voice = builder.execute().await?;
+
+        Ok(TTVCreatedVoice {
+            voice_id: voice.voice_id,
+            name: voice.name,
+            preview_url: voice.preview_url,
+        })
+    }
+}
+
+#[uniffi::export]
+impl TTVAudition {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Record that a preview was played
+    pub fn mark_played(&self, generated_voice_id: String) {
+        self.tracker.lock().unwrap().mark_played(generated_voice_id);
+    }
+
+    /// Record the chosen preview, which also counts as played
+    pub fn mark_selected(&self, generated_voice_id: String) {
+        self.tracker
+            .lock()
+            .unwrap()
+            .mark_selected(generated_voice_id);
+    }
+
+    pub fn selected(&self) -> Option<String> {
+        self.tracker.lock().unwrap().selected().map(str::to_string)
+    }
+
+    pub fn played(&self) -> Vec<String> {
+        self.tracker.lock().unwrap().played().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_voice_requires_a_selected_preview() {
+        let client = TTVClient::new("test-key".to_string());
+        let audition = TTVAudition::new();
+        audition.mark_played("gen-1".to_string());
+
+        let result = client
+            .create_voice(
+                "Narrator".to_string(),
+                "Calm, deep-voiced narrator".to_string(),
+                audition.clone(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ElevenLabsTTVError::ValidationError(_))
+        ));
+        assert_eq!(audition.played(), vec!["gen-1".to_string()]);
+        assert_eq!(audition.selected(), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! Character usage reported by the API in response headers
+
+use std::sync::Mutex;
+
+use reqwest::header::HeaderMap;
+
+use crate::{ElevenLabsTTVClient, RateLimitInfo, RawResponse};
+
+/// Usage reported in the headers of one response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageInfo {
+    /// Characters (credits) charged for the request, from the `character-cost` header
+    pub character_cost: Option<u64>,
+    /// ID of the request, from the `request-id` header, e.g. for support tickets
+    pub request_id: Option<String>,
+    /// Concurrency limits, from the `current-concurrent-requests` and
+    /// `maximum-concurrent-requests` headers
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+impl UsageInfo {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name: &str| -> Option<&str> { headers.get(name)?.to_str().ok() };
+
+        Self {
+            character_cost: header("character-cost")
+                .or_else(|| header("x-character-count"))
+                .and_then(|cost| cost.trim().parse().ok()),
+            request_id: header("request-id").map(str::to_string),
+            rate_limit: RateLimitInfo::from_headers(headers),
+        }
+    }
+}
+
+impl RawResponse {
+    /// Usage reported in the response headers
+    pub fn usage(&self) -> UsageInfo {
+        UsageInfo::from_headers(&self.headers)
+    }
+}
+
+/// Running totals of the usage reported to a client and its clones, see
+/// `ElevenLabsTTVClientBuilder::track_usage`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageTotals {
+    /// Responses received
+    pub requests: u64,
+    /// Characters (credits) charged, summed over the responses reporting them
+    pub characters: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct UsageTracker {
+    totals: Mutex<UsageTotals>,
+}
+
+impl UsageTracker {
+    pub(crate) fn record(&self, headers: &HeaderMap) {
+        let usage = UsageInfo::from_headers(headers);
+        let mut totals = self.totals.lock().unwrap();
+        totals.requests += 1;
+        totals.characters += usage.character_cost.unwrap_or(0);
+    }
+
+    fn take(&self) -> UsageTotals {
+        std::mem::take(&mut self.totals.lock().unwrap())
+    }
+}
+
+impl ElevenLabsTTVClient {
+    /// Usage reported since the client was built (or `reset_usage_totals` was called), if it
+    /// was built with `track_usage(true)`
+    pub fn usage_totals(&self) -> Option<UsageTotals> {
+        let usage = self.inner.usage.as_ref()?;
+        Some(*usage.totals.lock().unwrap())
+    }
+
+    /// Return the usage totals and start counting again from zero, e.g. once per job
+    pub fn reset_usage_totals(&self) -> Option<UsageTotals> {
+        self.inner.usage.as_ref().map(UsageTracker::take)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("character-cost", "312".parse().unwrap());
+        headers.insert("request-id", "req-1".parse().unwrap());
+
+        let usage = UsageInfo::from_headers(&headers);
+        assert_eq!(usage.character_cost, Some(312));
+        assert_eq!(usage.request_id.as_deref(), Some("req-1"));
+        assert_eq!(usage.rate_limit, None);
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .track_usage(true)
+            .build()
+            .unwrap();
+        client.inner.usage.as_ref().unwrap().record(&headers);
+        client
+            .inner
+            .usage
+            .as_ref()
+            .unwrap()
+            .record(&HeaderMap::new());
+        assert_eq!(
+            client.reset_usage_totals(),
+            Some(UsageTotals {
+                requests: 2,
+                characters: 312
+            })
+        );
+        assert_eq!(client.usage_totals(), Some(UsageTotals::default()));
+        assert_eq!(ElevenLabsTTVClient::new("test-key").usage_totals(), None);
+    }
+}
@@ -0,0 +1,348 @@
+use std::fmt;
+
+use crate::remix::RemixBuilder;
+use crate::{ElevenLabsTTVError, TTVCreateVoiceRequest, TTVDesignVoiceRequest};
+
+/// Shortest voice description accepted by the API, in characters
+pub const MIN_DESCRIPTION_LENGTH: usize = 20;
+
+/// Longest voice description accepted by the API, in characters
+pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
+
+/// One invalid request parameter, see `TTVDesignVoiceRequest::violations`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterViolation {
+    /// Name of the parameter, e.g. "loudness"
+    pub field: &'static str,
+    /// The rejected value
+    pub value: String,
+    /// What the API accepts, e.g. "-1 to 1"
+    pub allowed: String,
+}
+
+impl fmt::Display for ParameterViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {} (allowed: {})",
+            self.field, self.value, self.allowed
+        )
+    }
+}
+
+impl TTVDesignVoiceRequest {
+    /// Check the request parameters against the ranges accepted by the API.
+    /// The error lists every invalid parameter, not just the first one.
+    pub fn validate(&self) -> Result<(), ElevenLabsTTVError> {
+        into_result(self.violations())
+    }
+
+    /// All parameters outside the ranges accepted by the API
+    pub fn violations(&self) -> Vec<ParameterViolation> {
+        self.violations_with_reference_audio(
+            self.reference_audio_base64.is_some() || !self.reference_audios.is_empty(),
+        )
+    }
+
+    /// `violations`, for a request whose reference audio may be sent separately
+    /// (streamed from a file)
+    pub(crate) fn violations_with_reference_audio(
+        &self,
+        has_reference_audio: bool,
+    ) -> Vec<ParameterViolation> {
+        let mut violations = Vec::new();
+
+        // With eleven_ttv_v3, reference audio alone can drive the design
+        let reference_driven = has_reference_audio
+            && self.model_id.as_deref() == Some(crate::models::elevanlabs_models::ELEVEN_TTV_V3);
+        if reference_driven {
+            check_description_length(&mut violations, &self.voice_description, 0);
+        } else if self.voice_description.trim().is_empty() {
+            violations.push(required("voice_description"));
+        } else {
+            check_description_length(
+                &mut violations,
+                &self.voice_description,
+                MIN_DESCRIPTION_LENGTH,
+            );
+        }
+        check_text_length(&mut violations, self.text.as_deref());
+        check_range(&mut violations, "loudness", self.loudness, -1.0, 1.0);
+        check_range(&mut violations, "quality", self.quality, -1.0, 1.0);
+        check_range(
+            &mut violations,
+            "prompt_strength",
+            self.prompt_strength,
+            0.0,
+            1.0,
+        );
+        if self.reference_audio_base64.is_some() && !self.reference_audios.is_empty() {
+            violations.push(ParameterViolation {
+                field: "reference_audios",
+                value: format!("{} clip(s)", self.reference_audios.len()),
+                allowed: "either reference_audios or reference_audio_base64, not both".to_string(),
+            });
+        }
+        if let Some(reference_audio_base64) = &self.reference_audio_base64 {
+            violations.extend(crate::reference_audio::base64_violations(
+                reference_audio_base64,
+            ));
+        }
+        for reference_audio in &self.reference_audios {
+            violations.extend(crate::reference_audio::base64_violations(
+                &reference_audio.audio_base64,
+            ));
+            check_range(
+                &mut violations,
+                "reference_audios.weight",
+                reference_audio.weight,
+                0.0,
+                1.0,
+            );
+        }
+        check_guidance_scale(&mut violations, self.guidance_scale);
+
+        violations
+    }
+}
+
+impl RemixBuilder<'_> {
+    /// Check the remix parameters against the ranges accepted by the API.
+    /// The error lists every invalid parameter, not just the first one.
+    pub fn validate(&self) -> Result<(), ElevenLabsTTVError> {
+        into_result(self.violations())
+    }
+
+    /// All parameters outside the ranges accepted by the API
+    pub fn violations(&self) -> Vec<ParameterViolation> {
+        let mut violations = Vec::new();
+
+        // A remix prompt only describes the change, so it has no minimum length
+        if self.voice_description.trim().is_empty() {
+            violations.push(required("voice_description"));
+        } else {
+            check_description_length(&mut violations, &self.voice_description, 1);
+        }
+        check_text_length(&mut violations, self.text.as_deref());
+        check_range(&mut violations, "loudness", self.loudness, -1.0, 1.0);
+        check_range(
+            &mut violations,
+            "prompt_strength",
+            self.prompt_strength,
+            0.0,
+            1.0,
+        );
+        check_guidance_scale(&mut violations, self.guidance_scale);
+
+        violations
+    }
+}
+
+impl TTVCreateVoiceRequest {
+    /// Check that the required fields are set.
+    /// The error lists every invalid parameter, not just the first one.
+    pub fn validate(&self) -> Result<(), ElevenLabsTTVError> {
+        into_result(self.violations())
+    }
+
+    /// All required fields that are missing, and a description of the wrong length
+    pub fn violations(&self) -> Vec<ParameterViolation> {
+        let mut violations: Vec<ParameterViolation> = [
+            ("voice_name", &self.voice_name),
+            ("voice_description", &self.voice_description),
+            ("generated_voice_id", &self.generated_voice_id),
+        ]
+        .into_iter()
+        .filter(|(_, value)| value.trim().is_empty())
+        .map(|(field, _)| required(field))
+        .collect();
+
+        if !self.voice_description.trim().is_empty() {
+            check_description_length(
+                &mut violations,
+                &self.voice_description,
+                MIN_DESCRIPTION_LENGTH,
+            );
+        }
+        violations
+    }
+}
+
+fn check_range(
+    violations: &mut Vec<ParameterViolation>,
+    field: &'static str,
+    value: Option<f32>,
+    min: f32,
+    max: f32,
+) {
+    if let Some(value) = value
+        && !(min..=max).contains(&value)
+    {
+        violations.push(ParameterViolation {
+            field,
+            value: value.to_string(),
+            allowed: format!("{} to {}", min, max),
+        });
+    }
+}
+
+fn check_text_length(violations: &mut Vec<ParameterViolation>, text: Option<&str>) {
+    if let Some(text) = text {
+        let length = text.chars().count();
+        if !(100..=1000).contains(&length) {
+            violations.push(ParameterViolation {
+                field: "text",
+                value: format!("{} characters", length),
+                allowed: "100 to 1000 characters".to_string(),
+            });
+        }
+    }
+}
+
+fn check_guidance_scale(violations: &mut Vec<ParameterViolation>, guidance_scale: Option<u32>) {
+    if let Some(guidance_scale) = guidance_scale
+        && guidance_scale > 100
+    {
+        violations.push(ParameterViolation {
+            field: "guidance_scale",
+            value: guidance_scale.to_string(),
+            allowed: "0 to 100".to_string(),
+        });
+    }
+}
+
+fn check_description_length(
+    violations: &mut Vec<ParameterViolation>,
+    voice_description: &str,
+    min: usize,
+) {
+    let length = voice_description.chars().count();
+    if !(min..=MAX_DESCRIPTION_LENGTH).contains(&length) {
+        violations.push(ParameterViolation {
+            field: "voice_description",
+            value: format!("{} characters", length),
+            allowed: format!("{} to {} characters", min, MAX_DESCRIPTION_LENGTH),
+        });
+    }
+}
+
+fn required(field: &'static str) -> ParameterViolation {
+    ParameterViolation {
+        field,
+        value: "\"\"".to_string(),
+        allowed: "a non-empty string".to_string(),
+    }
+}
+
+/// A single `ValidationError` listing all violations
+pub(crate) fn into_result(violations: Vec<ParameterViolation>) -> Result<(), ElevenLabsTTVError> {
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let violations: Vec<String> = violations.iter().map(ToString::to_string).collect();
+    Err(ElevenLabsTTVError::ValidationError(format!(
+        "{} invalid parameter(s): {}",
+        violations.len(),
+        violations.join("; ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ElevenLabsTTVClient, ElevenLabsTTVError};
+
+    #[test]
+    fn test_build_validates_ranges() {
+        let client = ElevenLabsTTVClient::new("test-key");
+
+        let request = client
+            .design_voice("Nervous young wizard apprentice")
+            .loudness(0.2)
+            .build()
+            .unwrap();
+        assert_eq!(request.loudness, Some(0.2));
+        assert_eq!(request.guidance_scale, Some(5));
+
+        let error = client
+            .design_voice("Nervous young wizard apprentice")
+            .loudness(2.0)
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(error, ElevenLabsTTVError::ValidationError(msg) if msg.contains("loudness"))
+        );
+
+        let error = client
+            .design_voice("Nervous young wizard apprentice")
+            .text("Too short")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, ElevenLabsTTVError::ValidationError(msg) if msg.contains("text")));
+
+        assert!(client.create_voice("", "desc", "gen-1").build().is_err());
+
+        let error = client.design_voice("Wizard").build().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Validation error: 1 invalid parameter(s): voice_description = 6 characters \
+             (allowed: 20 to 1000 characters)"
+        );
+        let error = client
+            .create_voice("Merlin", "Wizard", "gen-1")
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(error, ElevenLabsTTVError::ValidationError(msg) if msg.contains("6 characters"))
+        );
+    }
+
+    #[test]
+    fn test_reference_audio_can_replace_the_description_on_v3() {
+        use crate::models::elevanlabs_models::ELEVEN_TTV_V3;
+
+        let client = ElevenLabsTTVClient::new("test-key");
+        let request = client
+            .design_voice("")
+            .model(ELEVEN_TTV_V3)
+            .reference_audio_base64("SUQzBAAAAAAAAA==")
+            .prompt_strength(0.0)
+            .build()
+            .unwrap();
+        assert_eq!(request.voice_description, "");
+
+        assert!(
+            client
+                .design_voice("")
+                .model(ELEVEN_TTV_V3)
+                .build()
+                .is_err()
+        );
+        assert!(
+            client
+                .design_voice("")
+                .reference_audio_base64("SUQzBAAAAAAAAA==")
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_all_violations_are_reported() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let error = client
+            .design_voice("Nervous young wizard apprentice")
+            .loudness(2.0)
+            .guidance_scale(150)
+            .text("Too short")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Validation error: 3 invalid parameter(s): text = 9 characters \
+             (allowed: 100 to 1000 characters); loudness = 2 (allowed: -1 to 1); \
+             guidance_scale = 150 (allowed: 0 to 100)"
+        );
+    }
+}
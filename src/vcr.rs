@@ -0,0 +1,415 @@
+//! Record and replay HTTP interactions ("VCR" cassettes), for deterministic integration tests
+//! and offline demos
+//!
+//! ```no_run
+//! use elevenlabs_ttv::vcr::VcrBackend;
+//! use elevenlabs_ttv::{ElevenLabsTTVClient, ReqwestBackend};
+//!
+//! # fn run() -> Result<(), elevenlabs_ttv::ElevenLabsTTVError> {
+//! // Once, against the live API
+//! let recorder = VcrBackend::record("tests/cassettes/design.json", ReqwestBackend::default());
+//! let client = ElevenLabsTTVClient::builder("your-api-key")
+//!     .http_backend(recorder)
+//!     .build()?;
+//!
+//! // Then, offline
+//! let client = ElevenLabsTTVClient::builder("unused")
+//!     .http_backend(VcrBackend::replay("tests/cassettes/design.json")?)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+use crate::ElevenLabsTTVError;
+use crate::backend::{BackendFuture, HttpBackend};
+
+/// Recorded request/response pairs, saved as JSON
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTVError> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Full URL, including the query string
+    pub uri: String,
+    pub headers: Vec<(String, String)>,
+    pub body: RecordedBody,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: RecordedBody,
+}
+
+/// A body, kept readable when it is UTF-8 text (e.g. JSON)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    Text(String),
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn new(body: &[u8]) -> Self {
+        match std::str::from_utf8(body) {
+            Ok(text) => RecordedBody::Text(text.to_string()),
+            Err(_) => RecordedBody::Base64(STANDARD.encode(body)),
+        }
+    }
+
+    fn to_bytes(&self) -> Result<Bytes, ElevenLabsTTVError> {
+        match self {
+            RecordedBody::Text(text) => Ok(Bytes::from(text.clone())),
+            RecordedBody::Base64(encoded) => STANDARD
+                .decode(encoded)
+                .map(Bytes::from)
+                .map_err(|e| ElevenLabsTTVError::BackendError(format!("invalid cassette: {}", e))),
+        }
+    }
+}
+
+enum Mode {
+    Record(Box<dyn HttpBackend>),
+    Replay,
+}
+
+/// `HttpBackend` recording every interaction to a cassette file, or replaying them from it
+///
+/// When recording, the cassette is rewritten after each interaction. API keys are left out
+/// of recordings unless `redact_api_key(false)` is set; `redact_audio(true)` also empties
+/// audio responses, multipart uploads and base64 audio fields, to keep cassettes small.
+/// Streamed request bodies (multipart uploads, `reference_audio_file`) are recorded in full.
+///
+/// When replaying, each request is answered by the first not yet replayed interaction with
+/// the same method and URL, and fails with a `BackendError` when there is none.
+pub struct VcrBackend {
+    mode: Mode,
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+    redact_api_key: bool,
+    redact_audio: bool,
+}
+
+impl VcrBackend {
+    /// Send requests with `backend` (e.g. `ReqwestBackend::default()`) and record them to `path`
+    pub fn record<P: Into<PathBuf>, B: HttpBackend + 'static>(path: P, backend: B) -> Self {
+        Self {
+            mode: Mode::Record(Box::new(backend)),
+            path: path.into(),
+            interactions: Mutex::new(Vec::new()),
+            redact_api_key: true,
+            redact_audio: false,
+        }
+    }
+
+    /// Answer requests with the interactions recorded in `path`
+    pub fn replay<P: Into<PathBuf>>(path: P) -> Result<Self, ElevenLabsTTVError> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+
+        Ok(Self {
+            mode: Mode::Replay,
+            path,
+            interactions: Mutex::new(cassette.interactions),
+            redact_api_key: true,
+            redact_audio: false,
+        })
+    }
+
+    /// Leave the `xi-api-key` and `Authorization` headers out of recordings. Defaults to true
+    pub fn redact_api_key(mut self, redact_api_key: bool) -> Self {
+        self.redact_api_key = redact_api_key;
+        self
+    }
+
+    /// Record audio responses, multipart uploads (e.g. voice samples) and base64 audio fields
+    /// of JSON bodies as empty. Defaults to false
+    pub fn redact_audio(mut self, redact_audio: bool) -> Self {
+        self.redact_audio = redact_audio;
+        self
+    }
+
+    fn record_interaction(
+        &self,
+        request: &http::Request<Bytes>,
+        response: &http::Response<Bytes>,
+    ) -> Result<(), ElevenLabsTTVError> {
+        let request_headers = request
+            .headers()
+            .iter()
+            .filter(|(name, _)| {
+                !self.redact_api_key || (*name != "xi-api-key" && *name != AUTHORIZATION)
+            })
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let response_headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let content_type_starts_with = |headers: &http::HeaderMap, prefix: &str| {
+            headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|content_type| content_type.starts_with(prefix))
+        };
+        let is_upload = content_type_starts_with(request.headers(), "multipart/");
+        let is_audio = content_type_starts_with(response.headers(), "audio/");
+
+        let interaction = Interaction {
+            request: RecordedRequest {
+                method: request.method().to_string(),
+                uri: request.uri().to_string(),
+                headers: request_headers,
+                body: self.recorded_body(request.body(), is_upload),
+            },
+            response: RecordedResponse {
+                status: response.status().as_u16(),
+                headers: response_headers,
+                body: self.recorded_body(response.body(), is_audio),
+            },
+        };
+
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.push(interaction);
+        Cassette {
+            interactions: interactions.clone(),
+        }
+        .save(&self.path)
+    }
+
+    fn recorded_body(&self, body: &[u8], is_audio: bool) -> RecordedBody {
+        if !self.redact_audio {
+            return RecordedBody::new(body);
+        }
+        if is_audio {
+            return RecordedBody::Text(String::new());
+        }
+
+        match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(mut json) => {
+                redact_base64_audio(&mut json);
+                RecordedBody::Text(json.to_string())
+            }
+            Err(_) => RecordedBody::new(body),
+        }
+    }
+
+    fn replay_interaction(
+        &self,
+        request: &http::Request<Bytes>,
+    ) -> Result<http::Response<Bytes>, ElevenLabsTTVError> {
+        let (method, uri) = (request.method().as_str(), request.uri().to_string());
+        let mut interactions = self.interactions.lock().unwrap();
+        let index = interactions
+            .iter()
+            .position(|interaction| {
+                interaction.request.method == method && interaction.request.uri == uri
+            })
+            .ok_or_else(|| {
+                ElevenLabsTTVError::BackendError(format!(
+                    "no recorded interaction left for {} {} in {}",
+                    method,
+                    uri,
+                    self.path.display()
+                ))
+            })?;
+        let recorded = interactions.remove(index).response;
+
+        let mut builder = http::Response::builder().status(recorded.status);
+        for (name, value) in &recorded.headers {
+            builder = builder.header(name, value);
+        }
+        builder
+            .body(recorded.body.to_bytes()?)
+            .map_err(|e| ElevenLabsTTVError::BackendError(e.to_string()))
+    }
+}
+
+impl HttpBackend for VcrBackend {
+    fn execute(&self, request: http::Request<Bytes>) -> BackendFuture<'_> {
+        Box::pin(async move {
+            match &self.mode {
+                Mode::Record(backend) => {
+                    let response = backend.execute(request.clone()).await?;
+                    self.record_interaction(&request, &response)?;
+                    Ok(response)
+                }
+                Mode::Replay => self.replay_interaction(&request),
+            }
+        })
+    }
+}
+
+/// Empty every string field named like `audio_base_64` or `reference_audio_base64`
+fn redact_base64_audio(json: &mut serde_json::Value) {
+    match json {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object {
+                if crate::redact::is_base64_audio_field(key) && value.is_string() {
+                    *value = serde_json::Value::String(String::new());
+                } else {
+                    redact_base64_audio(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_base64_audio),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ElevenLabsTTVClient, ReqwestBackend};
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "previews": [{
+                    "audio_base_64": "SUQzBAAAAAAAAA==",
+                    "generated_voice_id": "gen-1",
+                    "media_type": "audio/mpeg",
+                    "duration_secs": 4.2,
+                    "language": "en"
+                }],
+                "text": "Hello"
+            })))
+            .mount(&server)
+            .await;
+        let base_url = format!("{}/v1", server.uri());
+        let cassette = std::env::temp_dir().join(format!("ttv-vcr-{}.json", std::process::id()));
+
+        let recorder = VcrBackend::record(&cassette, ReqwestBackend::default()).redact_audio(true);
+        let client = ElevenLabsTTVClient::builder("secret-key")
+            .base_url(&base_url)
+            .http_backend(recorder)
+            .build()
+            .unwrap();
+        client
+            .design_voice("Calm, deep-voiced narrator")
+            .execute()
+            .await
+            .unwrap();
+        drop(server);
+
+        let recorded = fs::read_to_string(&cassette).unwrap();
+        assert!(!recorded.contains("secret-key"));
+        assert!(!recorded.contains("SUQzBAAAAAAAAA=="));
+
+        let replayer = VcrBackend::replay(&cassette).unwrap();
+        fs::remove_file(&cassette).unwrap();
+        let client = ElevenLabsTTVClient::builder("other-key")
+            .base_url(base_url)
+            .http_backend(replayer)
+            .build()
+            .unwrap();
+        let response = client
+            .design_voice("Calm, deep-voiced narrator")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.previews[0].generated_voice_id, "gen-1");
+        assert_eq!(response.previews[0].audio_base_64, "");
+
+        let error = client
+            .design_voice("Calm, deep-voiced narrator")
+            .execute()
+            .await;
+        assert!(matches!(error, Err(ElevenLabsTTVError::BackendError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_multipart() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc/voice-1/captcha"))
+            .and(body_string_contains("recorded captcha"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let base_url = format!("{}/v1", server.uri());
+        let cassette =
+            std::env::temp_dir().join(format!("ttv-vcr-multipart-{}.json", std::process::id()));
+        let recording =
+            std::env::temp_dir().join(format!("ttv-vcr-captcha-{}.mp3", std::process::id()));
+        fs::write(&recording, "recorded captcha").unwrap();
+
+        let recorder = VcrBackend::record(&cassette, ReqwestBackend::default());
+        let client = ElevenLabsTTVClient::builder("secret-key")
+            .base_url(&base_url)
+            .http_backend(recorder)
+            .build()
+            .unwrap();
+        let recorded = client
+            .submit_verification_recording("voice-1", &recording)
+            .await
+            .unwrap();
+        assert_eq!(recorded.status, "ok");
+        drop(server);
+
+        let saved = Cassette::load(&cassette).unwrap();
+        assert!(matches!(
+            &saved.interactions[0].request.body,
+            RecordedBody::Text(body) if body.contains("recorded captcha")
+        ));
+
+        let client = ElevenLabsTTVClient::builder("other-key")
+            .base_url(base_url)
+            .http_backend(VcrBackend::replay(&cassette).unwrap())
+            .build()
+            .unwrap();
+        let replayed = client
+            .submit_verification_recording("voice-1", &recording)
+            .await;
+        fs::remove_file(&cassette).unwrap();
+        fs::remove_file(&recording).unwrap();
+        assert_eq!(replayed.unwrap().status, "ok");
+    }
+}
@@ -0,0 +1,188 @@
+use std::path::{Path, PathBuf};
+
+use bytes::Bytes;
+use reqwest::multipart::Form;
+
+use crate::pvc::streamed_part;
+use crate::{ElevenLabsTTVClient, ElevenLabsTTVError, StatusResponse};
+
+/// Builder for manual verification requests of a Professional Voice Clone
+/// (POST /v1/voices/pvc/:voice_id/verification)
+pub struct VerificationRequestBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
+    pub voice_id: String,
+    pub extra_text: Option<String>,
+    files: Vec<PathBuf>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start building a manual verification request, with documents proving
+    /// the right to clone the voice
+    pub fn request_verification<S: Into<String>>(
+        &self,
+        voice_id: S,
+    ) -> VerificationRequestBuilder<'_> {
+        VerificationRequestBuilder {
+            client: self,
+            voice_id: voice_id.into(),
+            extra_text: None,
+            files: Vec::new(),
+        }
+    }
+
+    /// Get the captcha to read aloud for voice verification (GET /v1/voices/pvc/:voice_id/captcha)
+    ///
+    /// Returns the raw captcha payload as sent by the API.
+    pub async fn get_verification_captcha(
+        &self,
+        voice_id: &str,
+    ) -> Result<Bytes, ElevenLabsTTVError> {
+        let url = self.url(&["voices", "pvc", voice_id, "captcha"])?;
+
+        let response = self
+            .send("voices/pvc/{voice_id}/captcha", self.inner.client.get(url))
+            .await?;
+
+        Ok(response.bytes().await?)
+    }
+
+    /// Submit a recording of the captcha being read, to verify the voice
+    /// (POST /v1/voices/pvc/:voice_id/captcha)
+    pub async fn submit_verification_recording<P: AsRef<Path>>(
+        &self,
+        voice_id: &str,
+        recording: P,
+    ) -> Result<StatusResponse, ElevenLabsTTVError> {
+        let url = self.url(&["voices", "pvc", voice_id, "captcha"])?;
+        let form = Form::new().part("recording", streamed_part(recording.as_ref()).await?);
+
+        self.send_json(
+            "voices/pvc/{voice_id}/captcha",
+            self.inner.client.post(url).multipart(form),
+        )
+        .await
+    }
+}
+
+impl<'a> VerificationRequestBuilder<'a> {
+    /// Add a supporting document or recording from disk
+    pub fn add_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Extra text to display to the reviewer
+    pub fn extra_text<S: Into<String>>(mut self, extra_text: S) -> Self {
+        self.extra_text = Some(extra_text.into());
+        self
+    }
+
+    /// Execute the verification request
+    pub async fn execute(self) -> Result<StatusResponse, ElevenLabsTTVError> {
+        if self.files.is_empty() {
+            return Err(ElevenLabsTTVError::ValidationError(
+                "At least one file is required to request verification".to_string(),
+            ));
+        }
+
+        let mut form = Form::new();
+
+        if let Some(extra_text) = self.extra_text {
+            form = form.text("extra_text", extra_text);
+        }
+
+        for path in &self.files {
+            form = form.part("files", streamed_part(path).await?);
+        }
+
+        let url = self
+            .client
+            .url(&["voices", "pvc", &self.voice_id, "verification"])?;
+
+        self.client
+            .send_json(
+                "voices/pvc/{voice_id}/verification",
+                self.client.inner.client.post(url).multipart(form),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_verification() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc/voice-1/verification"))
+            .and(body_string_contains("name=\"extra_text\""))
+            .and(body_string_contains("Consent form attached"))
+            .and(body_string_contains("name=\"files\""))
+            .and(body_string_contains("signed consent"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let document =
+            std::env::temp_dir().join(format!("ttv-verification-{}.txt", std::process::id()));
+        std::fs::write(&document, "signed consent").unwrap();
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let result = client
+            .request_verification("voice-1")
+            .extra_text("Consent form attached")
+            .add_file(&document)
+            .execute()
+            .await;
+        std::fs::remove_file(&document).unwrap();
+
+        assert_eq!(result.unwrap().status, "ok");
+        assert!(matches!(
+            client.request_verification("voice-1").execute().await,
+            Err(ElevenLabsTTVError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verification_captcha() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/pvc/voice-1/captcha"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"captcha".as_slice()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc/voice-1/captcha"))
+            .and(body_string_contains("name=\"recording\""))
+            .and(body_string_contains("recorded captcha"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let recording =
+            std::env::temp_dir().join(format!("ttv-captcha-{}.mp3", std::process::id()));
+        std::fs::write(&recording, "recorded captcha").unwrap();
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+        let captcha = client.get_verification_captcha("voice-1").await.unwrap();
+        let result = client
+            .submit_verification_recording("voice-1", &recording)
+            .await;
+        std::fs::remove_file(&recording).unwrap();
+
+        assert_eq!(captcha, "captcha");
+        assert_eq!(result.unwrap().status, "ok");
+    }
+}
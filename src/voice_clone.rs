@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use reqwest::multipart::{Form, Part};
+
+use crate::{AddVoiceResponse, ElevenLabsTTVClient, ElevenLabsTTVError};
+
+/// A sample file to upload, either from disk or already in memory
+enum SampleFile {
+    Path(PathBuf),
+    Bytes { file_name: String, data: Vec<u8> },
+}
+
+/// Builder for Instant Voice Clone requests (POST /v1/voices/add)
+pub struct VoiceCloneBuilder<'a> {
+    client: &'a ElevenLabsTTVClient,
+    pub name: String,
+    pub description: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
+    pub remove_background_noise: Option<bool>,
+    files: Vec<SampleFile>,
+}
+
+impl ElevenLabsTTVClient {
+    /// Start building an Instant Voice Clone request
+    ///
+    /// Requires the name of the new voice and at least one sample file (`add_file`).
+    pub fn clone_voice<S: Into<String>>(&self, name: S) -> VoiceCloneBuilder<'_> {
+        VoiceCloneBuilder {
+            client: self,
+            name: name.into(),
+            description: None,
+            labels: None,
+            remove_background_noise: None,
+            files: Vec::new(),
+        }
+    }
+}
+
+impl<'a> VoiceCloneBuilder<'a> {
+    /// Add an audio sample from disk. The file is read when the request is executed.
+    pub fn add_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.files
+            .push(SampleFile::Path(path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Add an audio sample already in memory
+    pub fn add_file_bytes<S: Into<String>>(mut self, file_name: S, data: Vec<u8>) -> Self {
+        self.files.push(SampleFile::Bytes {
+            file_name: file_name.into(),
+            data,
+        });
+        self
+    }
+
+    /// Description of the voice
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a label (metadata) to the voice, e.g. ("accent", "Irish")
+    pub fn label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.labels
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Remove background noise from the samples using audio isolation.
+    /// If the samples do not include background noise, it can make the quality worse.
+    /// Defaults to false
+    pub fn remove_background_noise(mut self, remove_background_noise: bool) -> Self {
+        self.remove_background_noise = Some(remove_background_noise);
+        self
+    }
+
+    /// Execute the Instant Voice Clone request
+    pub async fn execute(self) -> Result<AddVoiceResponse, ElevenLabsTTVError> {
+        if self.files.is_empty() {
+            return Err(ElevenLabsTTVError::ValidationError(
+                "At least one sample file is required to clone a voice".to_string(),
+            ));
+        }
+
+        let mut form = Form::new().text("name", self.name);
+
+        if let Some(description) = self.description {
+            form = form.text("description", description);
+        }
+        if let Some(labels) = &self.labels {
+            form = form.text("labels", serde_json::to_string(labels)?);
+        }
+        if let Some(remove_background_noise) = self.remove_background_noise {
+            form = form.text(
+                "remove_background_noise",
+                remove_background_noise.to_string(),
+            );
+        }
+
+        for file in self.files {
+            form = form.part("files", sample_part(file).await?);
+        }
+
+        let url = self.client.url(&["voices", "add"])?;
+
+        self.client
+            .send_json(
+                "voices/add",
+                self.client.inner.client.post(url).multipart(form),
+            )
+            .await
+    }
+}
+
+/// Build a multipart file part, reading the file from disk if needed
+async fn sample_part(file: SampleFile) -> Result<Part, ElevenLabsTTVError> {
+    let (file_name, data) = match file {
+        SampleFile::Path(path) => {
+            #[cfg(feature = "tokio")]
+            let data = tokio::fs::read(&path).await?;
+            #[cfg(not(feature = "tokio"))]
+            let data = std::fs::read(&path)?;
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "sample".to_string());
+            (file_name, data)
+        }
+        SampleFile::Bytes { file_name, data } => (file_name, data),
+    };
+
+    let mime_type = audio_mime_type(&file_name);
+    Part::bytes(data)
+        .file_name(file_name)
+        .mime_str(mime_type)
+        .map_err(ElevenLabsTTVError::from)
+}
+
+/// Guess the audio MIME type from a file name's extension
+pub(crate) fn audio_mime_type(file_name: &str) -> &'static str {
+    let extension = Path::new(file_name)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("mp3") => "audio/mpeg",
+        Some("wav") => "audio/wav",
+        Some("ogg") | Some("opus") => "audio/ogg",
+        Some("flac") => "audio/flac",
+        Some("m4a") => "audio/mp4",
+        Some("webm") => "audio/webm",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_mime_type() {
+        assert_eq!(audio_mime_type("take1.MP3"), "audio/mpeg");
+        assert_eq!(audio_mime_type("dir/take2.wav"), "audio/wav");
+        assert_eq!(audio_mime_type("notes"), "application/octet-stream");
+    }
+
+    #[tokio::test]
+    async fn test_clone_requires_files() {
+        let client = ElevenLabsTTVClient::new("test-key");
+        let result = client
+            .clone_voice("Narrator")
+            .label("accent", "Irish")
+            .execute()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ElevenLabsTTVError::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt;
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+/// Gender of a designed voice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    Female,
+    Male,
+    Neutral,
+}
+
+/// Speaking pace of a designed voice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Pace {
+    Slow,
+    Moderate,
+    Fast,
+}
+
+/// Typed builder rendering a consistently structured voice description for `design_voice`
+///
+/// `client.design_voice(VoiceDescription::new().gender(Gender::Female).age_range(25..35)
+/// .accent("Irish").tone("warm, playful").pace(Pace::Fast))` sends
+/// "Female voice, aged 25 to 35, with an Irish accent. Warm, playful tone. Fast pace."
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VoiceDescription {
+    pub gender: Option<Gender>,
+    pub age_range: Option<Range<u32>>,
+    pub accent: Option<String>,
+    pub tone: Option<String>,
+    pub pace: Option<Pace>,
+    pub details: Vec<String>,
+}
+
+impl VoiceDescription {
+    /// An empty description
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gender of the voice
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// Apparent age of the speaker, e.g. `25..35` (rendered as "aged 25 to 35")
+    pub fn age_range(mut self, age_range: Range<u32>) -> Self {
+        self.age_range = Some(age_range);
+        self
+    }
+
+    /// Accent, e.g. "Irish" or "Southern American"
+    pub fn accent<S: Into<String>>(mut self, accent: S) -> Self {
+        self.accent = Some(accent.into());
+        self
+    }
+
+    /// Tone or character, e.g. "warm, playful"
+    pub fn tone<S: Into<String>>(mut self, tone: S) -> Self {
+        self.tone = Some(tone.into());
+        self
+    }
+
+    /// Speaking pace
+    pub fn pace(mut self, pace: Pace) -> Self {
+        self.pace = Some(pace);
+        self
+    }
+
+    /// Add a free-form sentence, e.g. "Studio-quality recording."
+    pub fn detail<S: Into<String>>(mut self, detail: S) -> Self {
+        self.details.push(detail.into());
+        self
+    }
+}
+
+impl fmt::Display for VoiceDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sentences = Vec::new();
+
+        let mut voice = match self.gender {
+            Some(Gender::Female) => "Female voice".to_string(),
+            Some(Gender::Male) => "Male voice".to_string(),
+            Some(Gender::Neutral) => "Gender-neutral voice".to_string(),
+            None => "Voice".to_string(),
+        };
+        if let Some(age_range) = &self.age_range {
+            voice.push_str(&format!(", aged {} to {}", age_range.start, age_range.end));
+        }
+        if let Some(accent) = &self.accent {
+            let article = match accent.chars().next().map(|c| c.to_ascii_lowercase()) {
+                Some('a' | 'e' | 'i' | 'o' | 'u') => "an",
+                _ => "a",
+            };
+            voice.push_str(&format!(", with {} {} accent", article, accent));
+        }
+        if voice != "Voice" {
+            sentences.push(voice);
+        }
+
+        if let Some(tone) = &self.tone {
+            sentences.push(format!("{} tone", capitalize(tone)));
+        }
+        if let Some(pace) = self.pace {
+            sentences.push(
+                match pace {
+                    Pace::Slow => "Slow pace",
+                    Pace::Moderate => "Moderate pace",
+                    Pace::Fast => "Fast pace",
+                }
+                .to_string(),
+            );
+        }
+        sentences.extend(
+            self.details
+                .iter()
+                .map(|detail| detail.trim_end_matches('.').to_string()),
+        );
+
+        let sentences: Vec<String> = sentences.into_iter().map(|s| s + ".").collect();
+        write!(f, "{}", sentences.join(" "))
+    }
+}
+
+impl From<VoiceDescription> for String {
+    fn from(description: VoiceDescription) -> Self {
+        description.to_string()
+    }
+}
+
+/// Descriptions shorter than this are linted as `DescriptionLint::TooShort`, in characters
+pub const LINT_MIN_DESCRIPTION_LENGTH: usize = 50;
+
+/// Descriptions longer than this are linted as `DescriptionLint::TooLong`, in characters
+pub const LINT_MAX_DESCRIPTION_LENGTH: usize = 600;
+
+/// Words hinting at the speaker's age
+const AGE_CUES: &[&str] = &[
+    "aged",
+    "age",
+    "years",
+    "year-old",
+    "young",
+    "younger",
+    "old",
+    "older",
+    "elderly",
+    "teen",
+    "teenage",
+    "teenager",
+    "child",
+    "kid",
+    "adult",
+    "middle-aged",
+    "senior",
+    "youthful",
+];
+
+/// Words hinting at the speaker's gender
+const GENDER_CUES: &[&str] = &[
+    "male",
+    "female",
+    "man",
+    "woman",
+    "boy",
+    "girl",
+    "masculine",
+    "feminine",
+    "gender-neutral",
+    "androgynous",
+    "he",
+    "she",
+    "his",
+    "her",
+];
+
+/// Words hinting at the speaker's accent
+const ACCENT_CUES: &[&str] = &["accent", "accented", "dialect", "native"];
+
+/// Adjectives describing opposite voices
+const CONTRADICTIONS: &[(&str, &str)] = &[
+    ("calm", "energetic"),
+    ("calm", "frantic"),
+    ("slow", "fast"),
+    ("deep", "high-pitched"),
+    ("soft", "loud"),
+    ("whispering", "shouting"),
+    ("warm", "cold"),
+    ("cheerful", "gloomy"),
+    ("young", "elderly"),
+    ("smooth", "raspy"),
+];
+
+/// A likely weakness of a voice description, following the ElevenLabs prompting guide:
+/// detailed descriptions naming age, gender and accent give the most consistent voices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptionLint {
+    /// Shorter than `LINT_MIN_DESCRIPTION_LENGTH` characters
+    TooShort {
+        length: usize,
+    },
+    /// Longer than `LINT_MAX_DESCRIPTION_LENGTH` characters
+    TooLong {
+        length: usize,
+    },
+    MissingAge,
+    MissingGender,
+    MissingAccent,
+    /// Two adjectives pulling the voice in opposite directions, e.g. "calm" and "energetic"
+    Contradictory(&'static str, &'static str),
+}
+
+impl fmt::Display for DescriptionLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DescriptionLint::TooShort { length } => write!(
+                f,
+                "description is short ({} characters); detailed descriptions of at least {} \
+                 characters give more consistent voices",
+                length, LINT_MIN_DESCRIPTION_LENGTH
+            ),
+            DescriptionLint::TooLong { length } => write!(
+                f,
+                "description is long ({} characters); details beyond {} characters tend to be \
+                 ignored",
+                length, LINT_MAX_DESCRIPTION_LENGTH
+            ),
+            DescriptionLint::MissingAge => write!(f, "no age cue, e.g. \"middle-aged\""),
+            DescriptionLint::MissingGender => write!(f, "no gender cue, e.g. \"female\""),
+            DescriptionLint::MissingAccent => write!(f, "no accent cue, e.g. \"Irish accent\""),
+            DescriptionLint::Contradictory(first, second) => {
+                write!(
+                    f,
+                    "contradictory adjectives \"{}\" and \"{}\"",
+                    first, second
+                )
+            }
+        }
+    }
+}
+
+/// Warnings about a voice description, e.g. to log before designing. Empty when the
+/// description looks complete. `design_voice(...).strict(true)` fails on any warning.
+pub fn lint_description(description: &str) -> Vec<DescriptionLint> {
+    let mut lints = Vec::new();
+
+    let length = description.trim().chars().count();
+    if length < LINT_MIN_DESCRIPTION_LENGTH {
+        lints.push(DescriptionLint::TooShort { length });
+    } else if length > LINT_MAX_DESCRIPTION_LENGTH {
+        lints.push(DescriptionLint::TooLong { length });
+    }
+
+    let lowercase = description.to_lowercase();
+    let words: Vec<&str> = lowercase
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .filter(|word| !word.is_empty())
+        .collect();
+    let mentions = |cues: &[&str]| words.iter().any(|word| cues.contains(word));
+
+    // "aged 30", "in her 40s"
+    let mentions_age = mentions(AGE_CUES)
+        || words
+            .iter()
+            .any(|word| word.starts_with(|c: char| c.is_ascii_digit()));
+    if !mentions_age {
+        lints.push(DescriptionLint::MissingAge);
+    }
+    if !mentions(GENDER_CUES) {
+        lints.push(DescriptionLint::MissingGender);
+    }
+    if !mentions(ACCENT_CUES) {
+        lints.push(DescriptionLint::MissingAccent);
+    }
+    for (first, second) in CONTRADICTIONS {
+        if words.contains(first) && words.contains(second) {
+            lints.push(DescriptionLint::Contradictory(first, second));
+        }
+    }
+
+    lints
+}
+
+/// Fail with a `ValidationError` listing the lints of `description`, for strict builders
+pub(crate) fn check_lints(description: &str) -> Result<(), crate::ElevenLabsTTVError> {
+    let lints = lint_description(description);
+    if lints.is_empty() {
+        return Ok(());
+    }
+
+    let lints: Vec<String> = lints.iter().map(ToString::to_string).collect();
+    Err(crate::ElevenLabsTTVError::ValidationError(format!(
+        "{} voice description warning(s): {}",
+        lints.len(),
+        lints.join("; ")
+    )))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_description() {
+        let description = VoiceDescription::new()
+            .gender(Gender::Female)
+            .age_range(25..35)
+            .accent("Irish")
+            .tone("warm, playful")
+            .pace(Pace::Fast)
+            .detail("Studio-quality recording.");
+
+        assert_eq!(
+            String::from(description),
+            "Female voice, aged 25 to 35, with an Irish accent. Warm, playful tone. Fast pace. \
+             Studio-quality recording."
+        );
+        assert_eq!(
+            VoiceDescription::new().tone("gravelly").to_string(),
+            "Gravelly tone."
+        );
+    }
+
+    #[test]
+    fn test_lint_description() {
+        assert_eq!(
+            lint_description("A calm but energetic narrator"),
+            [
+                DescriptionLint::TooShort { length: 29 },
+                DescriptionLint::MissingAge,
+                DescriptionLint::MissingGender,
+                DescriptionLint::MissingAccent,
+                DescriptionLint::Contradictory("calm", "energetic"),
+            ]
+        );
+
+        let rendered = VoiceDescription::new()
+            .gender(Gender::Female)
+            .age_range(25..35)
+            .accent("Irish")
+            .tone("warm, playful")
+            .to_string();
+        assert_eq!(lint_description(&rendered), []);
+
+        let client = crate::ElevenLabsTTVClient::new("test-key");
+        assert!(
+            client
+                .design_voice(rendered.as_str())
+                .strict(true)
+                .build()
+                .is_ok()
+        );
+        assert!(
+            client
+                .design_voice("Calm, deep-voiced narrator")
+                .strict(true)
+                .build()
+                .is_err()
+        );
+        assert!(
+            client
+                .design_voice("Calm, deep-voiced narrator")
+                .build()
+                .is_ok()
+        );
+    }
+}
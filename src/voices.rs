@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::{
+    ElevenLabsTTVClient, ElevenLabsTTVError, ListVoicesResponse, TTVCreateVoiceRequest, Voice,
+};
+
+impl ElevenLabsTTVClient {
+    /// List all voices available in the account (GET /v1/voices)
+    pub async fn list_voices(&self) -> Result<ListVoicesResponse, ElevenLabsTTVError> {
+        let url = self.url(&["voices"])?;
+
+        self.send_cached_json("voices", url).await
+    }
+
+    /// Get a single voice by ID (GET /v1/voices/:voice_id)
+    pub async fn get_voice(&self, voice_id: &str) -> Result<Voice, ElevenLabsTTVError> {
+        let url = self.url(&["voices", voice_id])?;
+
+        self.send_cached_json("voices/{voice_id}", url).await
+    }
+
+    /// Find an existing voice with the same fingerprint as a Create Voice request:
+    /// same name and, when the request has JSON object labels, the same labels.
+    pub async fn find_matching_voice(
+        &self,
+        request: &TTVCreateVoiceRequest,
+    ) -> Result<Option<Voice>, ElevenLabsTTVError> {
+        let voices = self.list_voices().await?;
+
+        Ok(voices
+            .voices
+            .into_iter()
+            .find(|voice| matches_fingerprint(voice, request)))
+    }
+}
+
+/// Whether an existing voice matches the name/labels fingerprint of a Create Voice request
+pub(crate) fn matches_fingerprint(voice: &Voice, request: &TTVCreateVoiceRequest) -> bool {
+    if voice.name.as_deref() != Some(request.voice_name.as_str()) {
+        return false;
+    }
+
+    let expected_labels = request
+        .labels
+        .as_deref()
+        .and_then(|labels| serde_json::from_str::<HashMap<String, String>>(labels).ok());
+
+    match expected_labels {
+        Some(expected) => {
+            let actual = voice.labels.clone().unwrap_or_default();
+            expected
+                .iter()
+                .all(|(key, value)| actual.get(key) == Some(value))
+        }
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voice(name: &str, labels: &[(&str, &str)]) -> Voice {
+        serde_json::from_value(serde_json::json!({
+            "voice_id": "voice-1",
+            "name": name,
+            "labels": labels.iter().cloned().collect::<HashMap<_, _>>(),
+        }))
+        .unwrap()
+    }
+
+    fn request(name: &str, labels: Option<&str>) -> TTVCreateVoiceRequest {
+        TTVCreateVoiceRequest {
+            voice_name: name.to_string(),
+            voice_description: "Grumpy dwarf blacksmith".to_string(),
+            generated_voice_id: "gen-1".to_string(),
+            labels: labels.map(str::to_string),
+            played_not_selected_voice_ids: None,
+            extra_body: serde_json::Map::new(),
+            extra_query: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_matches_fingerprint() {
+        let existing = voice("Borin", &[("project", "saga"), ("role", "npc")]);
+
+        assert!(matches_fingerprint(&existing, &request("Borin", None)));
+        assert!(matches_fingerprint(
+            &existing,
+            &request("Borin", Some(r#"{"project": "saga"}"#))
+        ));
+        assert!(!matches_fingerprint(
+            &existing,
+            &request("Borin", Some(r#"{"project": "other"}"#))
+        ));
+        assert!(!matches_fingerprint(&existing, &request("Dain", None)));
+    }
+}
@@ -53,4 +53,1330 @@ mod mock_tests {
         // For now, just test that the client can be created
         assert_eq!(true, true);
     }
+
+    #[tokio::test]
+    async fn test_execute_raw_returns_untyped_json() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(query_param("output_format", "mp3_44100_128"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-request-id", "req-1")
+                    .set_body_json(serde_json::json!({
+                        "previews": [],
+                        "text": "Hello",
+                        "new_field": 1
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let raw = client
+            .design_voice("Cheerful morning radio host")
+            .execute_raw()
+            .await
+            .unwrap();
+
+        assert_eq!(raw.status, 200);
+        assert_eq!(raw.headers["x-request-id"], "req-1");
+        assert_eq!(raw.body["new_field"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_prebuilt_request() {
+        use elevenlabs_ttv::TTVCreateVoiceRequest;
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice"))
+            .and(body_partial_json(
+                serde_json::json!({"voice_name": "Queued", "generated_voice_id": "gen-1"}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"voice_id": "voice-1"})),
+            )
+            .mount(&server)
+            .await;
+
+        let stored = r#"{"voice_name": "Queued", "voice_description": "Calm", "generated_voice_id": "gen-1"}"#;
+        let request: TTVCreateVoiceRequest = serde_json::from_str(stored).unwrap();
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let voice = client.execute_create_voice(request).await.unwrap();
+
+        assert_eq!(voice.voice_id, "voice-1");
+    }
+
+    #[tokio::test]
+    async fn test_api_key_rotation_on_rate_limit() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(header("xi-api-key", "key-1"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(header("xi-api-key", "key-2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "Hello"})),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("key-1")
+            .api_keys(["key-2"])
+            .base_url(format!("{}/v1", server.uri()))
+            .build()
+            .unwrap();
+        let response = client
+            .design_voice("Cheerful morning radio host")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(response.text, "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_limits_key_rotation() {
+        use elevenlabs_ttv::RetryBudget;
+        use std::time::Duration;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for (api_key, requests) in [("key-1", 1), ("key-2", 2)] {
+            Mock::given(method("GET"))
+                .and(path("/v1/voices"))
+                .and(header("xi-api-key", api_key))
+                .respond_with(ResponseTemplate::new(402))
+                .expect(requests)
+                .mount(&server)
+                .await;
+        }
+
+        let client = ElevenLabsTTVClient::builder("key-1")
+            .api_keys(["key-2"])
+            .base_url(format!("{}/v1", server.uri()))
+            .retry_budget(RetryBudget::new(0.0, Duration::from_secs(60)).min_retries(1))
+            .build()
+            .unwrap();
+        // The first call rotates to key-2; the second has no retry left
+        for _ in 0..2 {
+            assert!(matches!(
+                client.list_voices().await,
+                Err(ElevenLabsTTVError::ApiError { status: 402, .. })
+            ));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_max_concurrent_requests_is_shared_by_clones() {
+        use std::time::{Duration, Instant};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "voices": [] }))
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .max_concurrent_requests(1)
+            .build()
+            .unwrap();
+        let clone = client.clone();
+        let started = Instant::now();
+        let (first, second) = tokio::join!(client.list_voices(), clone.list_voices());
+        first.unwrap();
+        second.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_max_concurrent_requests_holds_the_slot_while_streaming() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Sends the headers right away and the body later, which wiremock cannot do
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let (server_in_flight, server_max) = (in_flight.clone(), max_in_flight.clone());
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let (in_flight, max_in_flight) = (server_in_flight.clone(), server_max.clone());
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0u8; 1024];
+                    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                        let read = socket.read(&mut buffer).await.unwrap();
+                        request.extend_from_slice(&buffer[..read]);
+                    }
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    socket
+                        .write_all(
+                            b"HTTP/1.1 200 OK\r\ncontent-type: audio/mpeg\r\n\
+                              content-length: 5\r\nconnection: close\r\n\r\n",
+                        )
+                        .await
+                        .unwrap();
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    socket.write_all(b"hello").await.unwrap();
+                });
+            }
+        });
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("http://{}/v1", address))
+            .max_concurrent_requests(1)
+            .build()
+            .unwrap();
+        let clone = client.clone();
+        let (first, second) = tokio::join!(
+            client.stream_preview("gen-1"),
+            clone.stream_preview("gen-2")
+        );
+
+        assert_eq!(first.unwrap(), "hello");
+        assert_eq!(second.unwrap(), "hello");
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_hooks() {
+        use std::sync::{Arc, Mutex};
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .and(header("xi-api-key", "key-1"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .and(header("xi-api-key", "key-2"))
+            .and(header("x-billing-project", "audiobook"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"audio".as_slice()))
+            .mount(&server)
+            .await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let (on_response, on_retry) = (events.clone(), events.clone());
+        let client = ElevenLabsTTVClient::builder("key-1")
+            .api_keys(["key-2"])
+            .base_url(format!("{}/v1", server.uri()))
+            .on_request(|_, request| {
+                request
+                    .headers_mut()
+                    .insert("x-billing-project", "audiobook".parse().unwrap());
+            })
+            .on_response(move |endpoint, response| {
+                on_response.lock().unwrap().push(format!(
+                    "{} {}",
+                    endpoint,
+                    response.status().as_u16()
+                ));
+            })
+            .on_retry(move |retry| {
+                on_retry
+                    .lock()
+                    .unwrap()
+                    .push(format!("retry {} {:?}", retry.attempt, retry.status));
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(client.stream_preview("gen-1").await.unwrap(), "audio");
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                "text-to-voice/{generated_voice_id}/stream 429",
+                "retry 2 Some(429)",
+                "text-to-voice/{generated_voice_id}/stream 200",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_includes_app_id() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .and(header(
+                "user-agent",
+                format!("{} my-game/1.2.0", elevenlabs_ttv::USER_AGENT).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"audio".as_slice()))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .app_id("my-game/1.2.0")
+            .base_url(format!("{}/v1", server.uri()))
+            .build()
+            .unwrap();
+
+        assert!(elevenlabs_ttv::USER_AGENT.starts_with("elevenlabs_ttv/"));
+        assert_eq!(client.stream_preview("gen-1").await.unwrap(), "audio");
+    }
+
+    #[tokio::test]
+    async fn test_get_voice_uses_etag_cache() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(serde_json::json!({"voice_id": "voice-1", "name": "Mila"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let first = client.get_voice("voice-1").await.unwrap();
+        let second = client.get_voice("voice-1").await.unwrap();
+
+        assert_eq!(first.name.as_deref(), Some("Mila"));
+        assert_eq!(second.name.as_deref(), Some("Mila"));
+    }
+
+    #[tokio::test]
+    async fn test_reference_audio_file_is_streamed() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let clip = std::env::temp_dir().join(format!("ttv-clip-{}.mp3", std::process::id()));
+        std::fs::write(&clip, b"ID3 reference clip").unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_json(serde_json::json!({
+                "voice_description": "Husky late-night jazz singer",
+                "model_id": "eleven_ttv_v3",
+                "text": null,
+                "auto_generate_text": true,
+                "loudness": 0.5,
+                "seed": null,
+                "guidance_scale": 5,
+                "stream_previews": false,
+                "remixing_session_id": null,
+                "remixing_session_iteration_id": null,
+                "quality": null,
+                "prompt_strength": null,
+                "reference_audio_base64": "SUQzIHJlZmVyZW5jZSBjbGlw"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "Hello"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let response = client
+            .design_voice("Husky late-night jazz singer")
+            .model(models::elevanlabs_models::ELEVEN_TTV_V3)
+            .reference_audio_file(&clip)
+            .execute()
+            .await;
+        std::fs::remove_file(&clip).unwrap();
+
+        assert_eq!(response.unwrap().text, "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_bearer_auth() {
+        use elevenlabs_ttv::AuthScheme;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .and(header("authorization", "Bearer short-lived-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"audio".as_slice()))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("short-lived-token")
+            .auth_scheme(AuthScheme::Bearer)
+            .base_url(format!("{}/v1", server.uri()))
+            .build()
+            .unwrap();
+        let audio = client.stream_preview("gen-1").await.unwrap();
+
+        assert_eq!(audio, "audio");
+        let requests = server.received_requests().await.unwrap();
+        assert!(requests[0].headers.get("xi-api-key").is_none());
+    }
+
+    #[cfg(feature = "middleware")]
+    #[tokio::test]
+    async fn test_requests_go_through_middleware() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .and(header("user-agent", "middleware-stack"))
+            .and(header("xi-api-key", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"audio".as_slice()))
+            .mount(&server)
+            .await;
+
+        let http = reqwest::Client::builder()
+            .user_agent("middleware-stack")
+            .build()
+            .unwrap();
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .middleware(reqwest_middleware::ClientBuilder::new(http).build())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.stream_preview("gen-1").await.unwrap(), "audio");
+    }
+
+    #[tokio::test]
+    async fn test_remix_wires_up_session() {
+        use wiremock::matchers::{body_partial_json, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/gen-1/remix"))
+            .and(query_param("output_format", "mp3_44100_128"))
+            .and(body_partial_json(serde_json::json!({
+                "voice_description": "make it 10 years older",
+                "remixing_session_iteration_id": null,
+                "auto_generate_text": true
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "previews": [{
+                    "audio_base_64": "", "generated_voice_id": "gen-2",
+                    "media_type": "audio/mpeg", "duration_secs": 1.0, "language": "en"
+                }],
+                "text": "Hello"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/gen-2/remix"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "Hello again"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let session = client
+            .remix("gen-1", "make it 10 years older")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(session.response.previews[0].generated_voice_id, "gen-2");
+
+        // The follow-up remix sets no IDs, yet lands in the same session
+        let next = session
+            .remix("gen-2", "now a little raspier")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(next.response.text, "Hello again");
+        assert_eq!(next.remixing_session_id, session.remixing_session_id);
+
+        let requests = server.received_requests().await.unwrap();
+        let bodies: Vec<serde_json::Value> = requests
+            .iter()
+            .map(|request| serde_json::from_slice(&request.body).unwrap())
+            .collect();
+        assert_eq!(
+            bodies[0]["remixing_session_id"],
+            session.remixing_session_id.as_str()
+        );
+        assert_eq!(
+            bodies[1]["remixing_session_id"],
+            session.remixing_session_id.as_str()
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_batch_resumes_from_checkpoint() {
+        use elevenlabs_ttv::TTVDesignVoiceRequest;
+        use elevenlabs_ttv::batch::{BatchEntry, BatchManifest, BatchState, EntryState};
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "previews": [{
+                    "audio_base_64": "",
+                    "generated_voice_id": "gen-witch",
+                    "media_type": "audio/mpeg",
+                    "duration_secs": 1.0,
+                    "language": "en"
+                }],
+                "text": "Hello"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        for (generated_voice_id, voice_id) in
+            [("gen-guard", "voice-guard"), ("gen-witch", "voice-witch")]
+        {
+            Mock::given(method("POST"))
+                .and(path("/v1/text-to-voice"))
+                .and(body_partial_json(
+                    serde_json::json!({ "generated_voice_id": generated_voice_id }),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!({ "voice_id": voice_id })),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        // A previous run designed the guard but crashed before creating it
+        let checkpoint =
+            std::env::temp_dir().join(format!("ttv-batch-resume-{}.json", std::process::id()));
+        let mut previous = BatchState::default();
+        previous.entries.insert(
+            "Guard".to_string(),
+            EntryState {
+                generated_voice_id: Some("gen-guard".to_string()),
+                ..Default::default()
+            },
+        );
+        previous.save(&checkpoint).unwrap();
+
+        let request = |description: &str| -> TTVDesignVoiceRequest {
+            serde_json::from_value(serde_json::json!({ "voice_description": description })).unwrap()
+        };
+        let manifest = BatchManifest::new(vec![
+            BatchEntry::new("Guard", request("Gruff, tired city guard")),
+            BatchEntry::new("Witch", request("Cackling old forest witch")),
+        ]);
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let state = client
+            .batch(manifest)
+            .checkpoint(&checkpoint)
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(BatchState::load(&checkpoint).unwrap(), state);
+        std::fs::remove_file(&checkpoint).unwrap();
+        assert_eq!(
+            state.created().collect::<Vec<_>>(),
+            [("Guard", "voice-guard"), ("Witch", "voice-witch")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_previews_preserves_order() {
+        use elevenlabs_ttv::TTVDesignVoiceResponse;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for id in ["gen-1", "gen-2", "gen-3"] {
+            Mock::given(method("GET"))
+                .and(path(format!("/v1/text-to-voice/{}/stream", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(id.as_bytes()))
+                .mount(&server)
+                .await;
+        }
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let previews: Vec<_> = ["gen-1", "gen-2", "gen-3"]
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "audio_base_64": "",
+                    "generated_voice_id": id,
+                    "media_type": "audio/mpeg",
+                    "duration_secs": 1.0,
+                    "language": null
+                })
+            })
+            .collect();
+        let response: TTVDesignVoiceResponse =
+            serde_json::from_value(serde_json::json!({ "previews": previews, "text": "Hello" }))
+                .unwrap();
+
+        let audio = response
+            .fetch_all_previews(&client)
+            .concurrency(2)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(audio, vec!["gen-1", "gen-2", "gen-3"]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_stream_preview_to_sink() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![7u8; 100_000]))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let mut sink = std::io::Cursor::new(Vec::new());
+        let written = client.stream_preview_to("gen-1", &mut sink).await.unwrap();
+
+        assert_eq!(written, 100_000);
+        assert_eq!(sink.into_inner(), vec![7u8; 100_000]);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_preview_response_forwards_the_stream() {
+        use axum::response::IntoResponse;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen1/stream"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(vec![7u8; 1000], "audio/ogg"))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let response = client
+            .preview_response("gen1")
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(response.headers()["content-type"], "audio/ogg");
+        assert_eq!(
+            response.headers()["content-disposition"],
+            "inline; filename=\"gen1.ogg\""
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body.len(), 1000);
+    }
+
+    #[cfg(feature = "tower")]
+    #[tokio::test]
+    async fn test_tower_service() {
+        use elevenlabs_ttv::{TTVDesignVoiceRequest, TTVDesignVoiceResponse};
+        use tower::Service;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "previews": [], "text": "Hello" })),
+            )
+            .mount(&server)
+            .await;
+
+        let mut client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let request = client
+            .design_voice("Calm, deep-voiced narrator")
+            .build()
+            .unwrap();
+        std::future::poll_fn(|cx| Service::<TTVDesignVoiceRequest>::poll_ready(&mut client, cx))
+            .await
+            .unwrap();
+        let response: TTVDesignVoiceResponse = client.call(request).await.unwrap();
+
+        assert_eq!(response.text, "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_sweep_guidance_scale_labels_results() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for guidance_scale in [2, 10] {
+            Mock::given(method("POST"))
+                .and(path("/v1/text-to-voice/design"))
+                .and(body_partial_json(
+                    serde_json::json!({ "guidance_scale": guidance_scale }),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "previews": [],
+                    "text": format!("scale {}", guidance_scale)
+                })))
+                .mount(&server)
+                .await;
+        }
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let results = client
+            .design_voice("Calm, deep-voiced narrator")
+            .seed(7)
+            .sweep_guidance_scale([10, 2])
+            .await
+            .unwrap();
+
+        assert_eq!(results[0].guidance_scale, 10);
+        assert_eq!(results[0].result.as_ref().unwrap().text, "scale 10");
+        assert_eq!(results[1].result.as_ref().unwrap().text, "scale 2");
+    }
+
+    #[tokio::test]
+    async fn test_errors_name_the_failed_endpoint() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .respond_with(ResponseTemplate::new(422).set_body_string("invalid seed"))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let error = client
+            .design_voice("Calm, deep-voiced narrator")
+            .output_format("mp3_44100_128")
+            .execute()
+            .await
+            .unwrap_err();
+
+        let context = error.context().unwrap();
+        assert_eq!(context.endpoint, "text-to-voice/design");
+        assert_eq!(
+            error.to_string(),
+            "API error (422) on POST /v1/text-to-voice/design?output_format=mp3_44100_128: invalid seed"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_quota() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/user/subscription"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tier": "creator",
+                "character_count": 95000,
+                "character_limit": 100000,
+                "next_character_count_reset_unix": 1714204800
+            })))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        assert_eq!(client.ensure_quota(5000).await.unwrap().tier, "creator");
+        assert!(matches!(
+            client.ensure_quota(5001).await,
+            Err(ElevenLabsTTVError::InsufficientQuotaError {
+                required: 5001,
+                remaining: 5000
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_model_fallback() {
+        use std::sync::{Arc, Mutex};
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_partial_json(
+                serde_json::json!({ "model_id": "eleven_ttv_v3" }),
+            ))
+            .respond_with(ResponseTemplate::new(422).set_body_string("unsupported loudness"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_partial_json(
+                serde_json::json!({ "model_id": "eleven_multilingual_ttv_v2" }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "v2"})),
+            )
+            .mount(&server)
+            .await;
+
+        let warnings = Arc::new(Mutex::new(Vec::new()));
+        let on_retry = warnings.clone();
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .model_fallback(true)
+            .on_retry(move |retry| on_retry.lock().unwrap().push(retry.reason.clone()))
+            .build()
+            .unwrap();
+        let response = client
+            .design_voice("Calm, deep-voiced narrator")
+            .model(models::elevanlabs_models::ELEVEN_TTV_V3)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(response.text, "v2");
+        assert_eq!(
+            *warnings.lock().unwrap(),
+            [
+                "eleven_ttv_v3 rejected the request (unsupported loudness), falling back to \
+              eleven_multilingual_ttv_v2"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_model_fallback_drops_v3_only_fields() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_partial_json(
+                serde_json::json!({ "model_id": "eleven_ttv_v3" }),
+            ))
+            .respond_with(ResponseTemplate::new(422).set_body_string("unsupported reference"))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_partial_json(serde_json::json!({
+                "model_id": "eleven_multilingual_ttv_v2",
+                "voice_description": "Calm, deep-voiced narrator",
+                "reference_audio_base64": null,
+                "prompt_strength": null
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "v2"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .model_fallback(true)
+            .build()
+            .unwrap();
+        let response = client
+            .design_voice("Calm, deep-voiced narrator")
+            .model(models::elevanlabs_models::ELEVEN_TTV_V3)
+            .reference_audio_base64("SUQzBAAAAAAAAA==")
+            .prompt_strength(0.5)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.text, "v2");
+
+        // Without a description, v2 has nothing to design from
+        let error = client
+            .design_voice("")
+            .model(models::elevanlabs_models::ELEVEN_TTV_V3)
+            .reference_audio_base64("SUQzBAAAAAAAAA==")
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            ElevenLabsTTVError::ApiError { status: 422, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_model_fallback_with_reference_audio_file() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let clip =
+            std::env::temp_dir().join(format!("ttv-fallback-clip-{}.mp3", std::process::id()));
+        std::fs::write(&clip, b"ID3 reference clip").unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_partial_json(serde_json::json!({
+                "model_id": "eleven_ttv_v3",
+                "reference_audio_base64": "SUQzIHJlZmVyZW5jZSBjbGlw"
+            })))
+            .respond_with(ResponseTemplate::new(400).set_body_string("unsupported reference"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-voice/design"))
+            .and(body_partial_json(serde_json::json!({
+                "model_id": "eleven_multilingual_ttv_v2",
+                "voice_description": "Husky late-night jazz singer",
+                "reference_audio_base64": null
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"previews": [], "text": "v2"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .model_fallback(true)
+            .build()
+            .unwrap();
+        let response = client
+            .design_voice("Husky late-night jazz singer")
+            .model(models::elevanlabs_models::ELEVEN_TTV_V3)
+            .reference_audio_file(&clip)
+            .execute()
+            .await;
+        std::fs::remove_file(&clip).unwrap();
+
+        assert_eq!(response.unwrap().text, "v2");
+    }
+
+    #[tokio::test]
+    async fn test_check_model() {
+        use elevenlabs_ttv::ModelStatus;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "model_id": "eleven_flash_v2_5", "name": "Eleven Flash v2.5" },
+                { "model_id": "eleven_monolingual_v1", "name": "Eleven English v1" }
+            ])))
+            .mount(&server)
+            .await;
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+
+        assert_eq!(client.list_models().await.unwrap().len(), 2);
+        assert_eq!(
+            client
+                .check_model(Some("eleven_monolingual_v1"))
+                .await
+                .unwrap(),
+            ModelStatus::Deprecated
+        );
+        assert_eq!(
+            client.check_model(Some("eleven_ttv_v3")).await.unwrap(),
+            ModelStatus::Available
+        );
+        assert!(matches!(
+            client.check_model(Some("eleven_ttv_v1")).await,
+            Err(ElevenLabsTTVError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_find_similar_voices() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/similar-voices"))
+            .and(body_string_contains(
+                "name=\"audio_file\"; filename=\"reference.mp3\"",
+            ))
+            .and(body_string_contains("name=\"top_k\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "voices": [{
+                    "public_owner_id": "owner-1",
+                    "voice_id": "voice-1",
+                    "name": "Calm Narrator",
+                    "category": "professional",
+                    "language": "en"
+                }],
+                "has_more": false
+            })))
+            .mount(&server)
+            .await;
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", server.uri()));
+
+        let response = client
+            .find_similar_voices(b"ID3\x04\x00\x00".to_vec())
+            .top_k(5)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.voices[0].voice_id, "voice-1");
+        assert!(matches!(
+            client.find_similar_voices(Vec::new()).execute().await,
+            Err(ElevenLabsTTVError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        use elevenlabs_ttv::CircuitState;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "voices": [] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::builder("test-key")
+            .base_url(format!("{}/v1", server.uri()))
+            .circuit_breaker(2, Duration::from_millis(200))
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            assert!(matches!(
+                client.list_voices().await,
+                Err(ElevenLabsTTVError::ApiError { status: 503, .. })
+            ));
+        }
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+        assert!(matches!(
+            client.clone().list_voices().await,
+            Err(ElevenLabsTTVError::CircuitOpenError { .. })
+        ));
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(client.circuit_state(), Some(CircuitState::HalfOpen));
+        client.list_voices().await.unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+    }
+
+    // Without tokio, requests during the cooldown fail instead of waiting
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_rate_limit_cooldown_is_shared_by_clones() {
+        use std::time::{Duration, Instant};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/text-to-voice/gen-1/stream"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"audio".as_slice()))
+            .mount(&server)
+            .await;
+
+        let client = ElevenLabsTTVClient::with_base_url(
+            "test-key".to_string(),
+            format!("{}/v1", server.uri()),
+        );
+        let clone = client.clone();
+        assert!(client.stream_preview("gen-1").await.is_err());
+        assert!(clone.rate_limit_cooldown().is_some());
+
+        let started = Instant::now();
+        assert_eq!(clone.stream_preview("gen-1").await.unwrap(), "audio");
+        assert!(started.elapsed() >= Duration::from_millis(500));
+        assert_eq!(client.rate_limit_cooldown(), None);
+    }
+
+    #[tokio::test]
+    async fn test_history_fetch_all_follows_pages() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history"))
+            .and(query_param("start_after_history_item_id", "item-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "history": [{ "history_item_id": "item-3" }],
+                "last_history_item_id": "item-3",
+                "has_more": false
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "history": [{ "history_item_id": "item-1" }, { "history_item_id": "item-2" }],
+                "last_history_item_id": "item-2",
+                "has_more": true
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", mock_server.uri()));
+        let items = client.history().page_size(2).fetch_all().await.unwrap();
+
+        let ids: Vec<_> = items
+            .iter()
+            .map(|item| item.history_item_id.as_str())
+            .collect();
+        assert_eq!(ids, ["item-1", "item-2", "item-3"]);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(
+            !requests[0]
+                .url
+                .query()
+                .unwrap_or("")
+                .contains("start_after")
+        );
+        assert!(
+            requests[1]
+                .url
+                .query()
+                .unwrap()
+                .contains("start_after_history_item_id=item-2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_history_fetch_all_stops_when_cursor_is_stuck() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "history": [{ "history_item_id": "item-1" }],
+                "last_history_item_id": "item-1",
+                "has_more": true
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            ElevenLabsTTVClient::with_base_url("test-key", &format!("{}/v1", mock_server.uri()));
+        let error = client.history().fetch_all().await.unwrap_err();
+
+        assert!(
+            matches!(&error, ElevenLabsTTVError::ApiError { message, .. } if message.contains("item-1")),
+            "{:?}",
+            error
+        );
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+}
+
+#[test]
+fn test_request_roundtrip() {
+    use elevenlabs_ttv::{TTVCreateVoiceRequest, TTVDesignVoiceRequest};
+
+    let json =
+        r#"{"voice_description": "Gravelly old pirate captain", "seed": 7, "guidance_scale": 10}"#;
+    let design: TTVDesignVoiceRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(design.seed, Some(7));
+    assert_eq!(design.model_id, None);
+    let reparsed: TTVDesignVoiceRequest =
+        serde_json::from_str(&serde_json::to_string(&design).unwrap()).unwrap();
+    assert_eq!(design, reparsed);
+
+    let create = TTVCreateVoiceRequest {
+        voice_name: "Captain".to_string(),
+        voice_description: "Gravelly old pirate captain".to_string(),
+        generated_voice_id: "gen-1".to_string(),
+        labels: None,
+        played_not_selected_voice_ids: Some(vec!["gen-2".to_string()]),
+        extra_body: serde_json::Map::new(),
+        extra_query: Vec::new(),
+    };
+    let reparsed: TTVCreateVoiceRequest =
+        serde_json::from_str(&serde_json::to_string(&create).unwrap()).unwrap();
+    assert_eq!(create, reparsed);
+}
+
+#[test]
+fn test_preview_decode_audio() {
+    use elevenlabs_ttv::TTVDesignVoiceResponseVoicePreview;
+
+    let mut preview = TTVDesignVoiceResponseVoicePreview {
+        audio_base_64: "SUQzBAA=".to_string(),
+        generated_voice_id: "gen-1".to_string(),
+        media_type: "audio/mpeg".into(),
+        duration_secs: 1.0,
+        language: None,
+    };
+    assert_eq!(preview.decode_audio().unwrap().as_ref(), b"ID3\x04\x00");
+    assert_eq!(preview.duration(), std::time::Duration::from_secs(1));
+
+    preview.audio_base_64 = "not base64!".to_string();
+    assert!(matches!(
+        preview.decode_audio(),
+        Err(ElevenLabsTTVError::AudioDecodeError(_))
+    ));
+}
+
+#[test]
+fn test_history_response_parsing() {
+    use elevenlabs_ttv::HistoryResponse;
+
+    let json = r#"{
+        "history": [{
+            "history_item_id": "item-1",
+            "voice_id": "voice-1",
+            "voice_category": "generated",
+            "character_count_change_from": 100,
+            "character_count_change_to": 142,
+            "date_unix": 1714650306
+        }],
+        "last_history_item_id": "item-1",
+        "has_more": false
+    }"#;
+    let page: HistoryResponse = serde_json::from_str(json).unwrap();
+
+    assert_eq!(page.history.len(), 1);
+    assert_eq!(page.history[0].character_count(), 42);
+    assert!(!page.has_more);
+}
+
+#[test]
+fn test_unknown_enum_values_are_tolerated() {
+    use elevenlabs_ttv::{SafetyControl, TTVCreateVoiceResponse, VoiceCategory};
+
+    let json = r#"{
+        "voice_id": "voice-1",
+        "category": "some_future_category",
+        "safety_control": "SOME_FUTURE_CONTROL",
+        "sharing": {"status": "enabled", "review_status": "escalated"}
+    }"#;
+    let voice: TTVCreateVoiceResponse = serde_json::from_str(json).unwrap();
+
+    assert!(matches!(voice.category, Some(VoiceCategory::Unknown)));
+    assert!(matches!(voice.safety_control, Some(SafetyControl::Unknown)));
+    assert!(voice.is_shared());
 }